@@ -69,23 +69,14 @@ const _: () = {
     //
     // See ./struct-default-expanded.rs and https://github.com/taiki-e/pin-project/pull/53.
     // for details.
-    struct __Enum<'pin, T, U> {
-        __pin_project_use_generics: ::pin_project::__private::AlwaysUnpin<
-            'pin,
-            (::pin_project::__private::PhantomData<T>, ::pin_project::__private::PhantomData<U>),
-        >,
-        __field0: T,
-    }
-    impl<'pin, T, U> ::pin_project::__private::Unpin for Enum<T, U> where
-        ::pin_project::__private::PinnedFieldsOf<__Enum<'pin, T, U>>:
-            ::pin_project::__private::Unpin
+    impl<T, U> ::pin_project::__private::Unpin for Enum<T, U> where
+        ::pin_project::__private::PinnedFieldsOf<T>: ::pin_project::__private::Unpin
     {
     }
     // A dummy impl of `UnsafeUnpin`, to ensure that the user cannot implement it.
     #[doc(hidden)]
-    unsafe impl<'pin, T, U> ::pin_project::UnsafeUnpin for Enum<T, U> where
-        ::pin_project::__private::PinnedFieldsOf<__Enum<'pin, T, U>>:
-            ::pin_project::__private::Unpin
+    unsafe impl<T, U> ::pin_project::UnsafeUnpin for Enum<T, U> where
+        ::pin_project::__private::PinnedFieldsOf<T>: ::pin_project::__private::Unpin
     {
     }
 
@@ -103,34 +103,36 @@ const _: () = {
     // impl<T, U> Unpin for Struct<T, U> where T: Unpin {}
     // ```
     //
-    // However, if struct is public and there is a private type field,
-    // this would cause an E0446 (private type in public interface).
+    // However, a bare `T: Unpin` bound allows the user to write an overlapping
+    // `Unpin` impl for a type whose pinned field is never `Unpin`. The type
+    // parameter is therefore wrapped in `PinnedFieldsOf`, which implements
+    // `Unpin` iff `T` implements `Unpin`, but cannot be used in such impls.
+    //
+    // See https://github.com/taiki-e/pin-project/issues/340 for details.
+    //
+    // This form is used when all pinned fields are type parameters. Otherwise,
+    // using the types of the pinned fields in the bounds would cause an E0446
+    // (private type in public interface) if struct is public and there is a
+    // private type field.
     //
     // When RFC 2145 is implemented (rust-lang/rust#48054),
     // this will become a lint, rather than a hard error.
     //
-    // As a workaround for this, we generate a new struct, containing all of
-    // the pinned fields from our #[pin_project] type. This struct is declared
-    // within a function, which makes it impossible to be named by user code.
-    // This guarantees that it will use the default auto-trait impl for Unpin -
-    // that is, it will implement Unpin iff all of its fields implement Unpin.
-    // This type can be safely declared as 'public', satisfying the privacy
-    // checker without actually allowing user code to access it.
+    // As a workaround for this, in that case we generate a new struct,
+    // containing all of the pinned fields from our #[pin_project] type. This
+    // struct is declared within a function, which makes it impossible to be
+    // named by user code. This guarantees that it will use the default
+    // auto-trait impl for Unpin - that is, it will implement Unpin iff all of
+    // its fields implement Unpin. This type can be safely declared as 'public',
+    // satisfying the privacy checker without actually allowing user code to
+    // access it.
     //
     // This allows users to apply the #[pin_project] attribute to types
     // regardless of the privacy of the types of their fields.
     //
     // See also https://github.com/taiki-e/pin-project/pull/53.
-    struct __Struct<'pin, T, U> {
-        __pin_project_use_generics: ::pin_project::__private::AlwaysUnpin<
-            'pin,
-            (::pin_project::__private::PhantomData<T>, ::pin_project::__private::PhantomData<U>),
-        >,
-        __field0: T,
-    }
-    impl<'pin, T, U> ::pin_project::__private::Unpin for Struct<T, U> where
-        ::pin_project::__private::PinnedFieldsOf<__Struct<'pin, T, U>>:
-            ::pin_project::__private::Unpin
+    impl<T, U> ::pin_project::__private::Unpin for Struct<T, U> where
+        ::pin_project::__private::PinnedFieldsOf<T>: ::pin_project::__private::Unpin
     {
     }
     // A dummy impl of `UnsafeUnpin`, to ensure that the user cannot implement it.
@@ -140,9 +142,8 @@ const _: () = {
     // impl, they'll get a "conflicting implementations of trait" error when
     // coherence checks are run.
     #[doc(hidden)]
-    unsafe impl<'pin, T, U> ::pin_project::UnsafeUnpin for Struct<T, U> where
-        ::pin_project::__private::PinnedFieldsOf<__Struct<'pin, T, U>>:
-            ::pin_project::__private::Unpin
+    unsafe impl<T, U> ::pin_project::UnsafeUnpin for Struct<T, U> where
+        ::pin_project::__private::PinnedFieldsOf<T>: ::pin_project::__private::Unpin
     {
     }
 
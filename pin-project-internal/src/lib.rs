@@ -71,6 +71,30 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// If `_` is passed instead of a name, the name is inferred from the name of
+/// the original type: `project = _`, `project_ref = _`, and
+/// `project_replace = _` name the projection types `{Type}Proj`,
+/// `{Type}ProjRef`, and `{Type}ProjOwn`, respectively.
+///
+/// ```
+/// # use pin_project::pin_project;
+/// # use std::pin::Pin;
+/// #[pin_project(project = _)]
+/// enum Enum<T> {
+///     Variant(#[pin] T),
+/// }
+///
+/// impl<T> Enum<T> {
+///     fn method(self: Pin<&mut Self>) {
+///         match self.project() {
+///             EnumProj::Variant(x) => {
+///                 let _: Pin<&mut T> = x;
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
 /// Note that the projection types returned by `project` and `project_ref` have
 /// an additional lifetime at the beginning of generics.
 ///
@@ -82,7 +106,11 @@ use proc_macro::TokenStream;
 /// The visibility of the projected types and projection methods is based on the
 /// original type. However, if the visibility of the original type is `pub`, the
 /// visibility of the projected types and the projection methods is downgraded
-/// to `pub(crate)`.
+/// to `pub(crate)`. To override this, see the [`project_vis`](#project_vis)
+/// argument.
+///
+/// To also implement the [`PinProject`] trait for the original type, see the
+/// [`PinProject`](#pinproject) argument.
 ///
 /// # Safety
 ///
@@ -312,6 +340,13 @@ use proc_macro::TokenStream;
 ///
 /// Note that using [`PhantomPinned`] without `#[pin]` attribute has no effect.
 ///
+/// By passing the `negative_impl` argument in addition to `!Unpin` (i.e.,
+/// `#[pin_project(!Unpin, negative_impl)]`), a negative impl
+/// (`impl !Unpin for Struct<T>`) is generated instead of an [`Unpin`] impl with
+/// bounds that are never satisfied. This requires nightly Rust and
+/// `#![feature(negative_impls)]` in the crate that uses the argument. Note that
+/// this argument is outside of the normal semver guarantees.
+///
 /// # `UnsafeUnpin`
 ///
 /// If you want to implement [`Unpin`] manually, you must use the `UnsafeUnpin`
@@ -340,6 +375,30 @@ use proc_macro::TokenStream;
 ///
 /// See [`UnsafeUnpin`] trait for more details.
 ///
+/// # `Unpin where`
+///
+/// If you only want to add some conditions to the automatically generated
+/// [`Unpin`] impl, use the `Unpin where <predicates>` argument instead of
+/// `UnsafeUnpin`. The predicates are added to the where clause of the
+/// generated [`Unpin`] impl, in addition to the [`Unpin`] bounds for the
+/// pinned fields. This is always safe, because it can only make the impl
+/// narrower.
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(Unpin where U: Clone, Self: Send)]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: U,
+/// }
+/// ```
+///
+/// Since where predicates can contain commas, this argument must be the last
+/// argument to `#[pin_project]`. It cannot be used together with the
+/// `UnsafeUnpin` or `!Unpin` argument.
+///
 /// # `#[pinned_drop]`
 ///
 /// In order to correctly implement pin projections, a type's [`Drop`] impl must
@@ -399,6 +458,59 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// Like [`Drop::drop`], `PinnedDrop::drop` cannot move fields out of `self`,
+/// because all fields are still dropped after it returns. To hand off an
+/// unpinned field by value during destruction, wrap it in [`Option`] and take
+/// it out:
+///
+/// ```
+/// use std::{pin::Pin, sync::mpsc::Sender};
+///
+/// use pin_project::{pin_project, pinned_drop};
+///
+/// #[pin_project(PinnedDrop)]
+/// struct NotifyOnDrop<T> {
+///     #[pin]
+///     pinned_field: T,
+///     sender: Option<Sender<Vec<u8>>>,
+///     buf: Vec<u8>,
+/// }
+///
+/// #[pinned_drop]
+/// impl<T> PinnedDrop for NotifyOnDrop<T> {
+///     fn drop(self: Pin<&mut Self>) {
+///         let this = self.project();
+///         if let Some(sender) = this.sender.take() {
+///             let _ = sender.send(std::mem::take(this.buf));
+///         }
+///     }
+/// }
+/// ```
+///
+/// Instead of a `#[pinned_drop]` impl block, you can also pass the path to a
+/// function to the `PinnedDrop` argument. The function must be callable as
+/// `fn(Pin<&mut Self>)`, and it cannot be an `unsafe fn`. Generic functions
+/// can be shared between several types:
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// fn log_drop<T: ?Sized>(_this: Pin<&mut T>) {
+///     println!("Dropping {}", std::any::type_name::<T>());
+/// }
+///
+/// #[pin_project(PinnedDrop = log_drop)]
+/// struct Struct<T> {
+///     #[pin]
+///     pinned_field: T,
+/// }
+///
+/// #[pin_project(PinnedDrop = log_drop)]
+/// struct TupleStruct<T>(#[pin] T);
+/// ```
+///
 /// See also [`#[pinned_drop]`][macro@pinned_drop] attribute.
 ///
 /// # `project_replace` method
@@ -420,10 +532,13 @@ use proc_macro::TokenStream;
 /// The `ProjectionOwned` type is identical to the `Self` type, except that
 /// all pinned fields have been replaced by equivalent [`PhantomData`] types.
 ///
-/// This method is opt-in, because it is only supported for [`Sized`] types, and
-/// because it is incompatible with the [`#[pinned_drop]`][pinned-drop]
-/// attribute described above. It can be enabled by using
-/// `#[pin_project(project_replace)]`.
+/// This method is opt-in, because it is only supported for [`Sized`] types.
+/// It can be enabled by using `#[pin_project(project_replace)]`.
+///
+/// If the type also uses the [`#[pinned_drop]`][pinned-drop] attribute
+/// described above, `PinnedDrop::drop` is called on the old value before its
+/// unpinned fields are moved out. So, in the same way as with [`Pin::set`],
+/// the old value is always "dropped" before being replaced.
 ///
 /// For example:
 ///
@@ -474,9 +589,647 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// By passing the `project_take` argument in addition to `project_replace`,
+/// a `project_take` method is also generated. It is equivalent to calling
+/// `project_replace` with [`Default::default()`], and is only available if
+/// the type implements [`Default`].
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project_replace = StateProjOwn, project_take)]
+/// #[derive(Default)]
+/// enum State<T> {
+///     Pending(#[pin] T, &'static str),
+///     #[default]
+///     Done,
+/// }
+///
+/// let mut x = Box::pin(State::Pending(42, "hello"));
+///
+/// match x.as_mut().project_take() {
+///     StateProjOwn::Pending(_, s) => assert_eq!(s, "hello"),
+///     StateProjOwn::Done => unreachable!(),
+/// }
+/// assert!(matches!(*x, State::Done));
+/// ```
+///
+/// By passing the `project_replace_with` argument in addition to
+/// `project_replace`, a `project_replace_with` method is also generated. It
+/// takes a closure that is called with the projection of the old value (the same type that the
+/// `project` method returns) before its unpinned fields are moved out and its
+/// pinned fields are dropped in place. This can be used, for example, to flush
+/// a pinned writer before it is dropped.
+///
+/// ```
+/// # use std::pin::Pin;
+/// # type Projection<'a> = &'a ();
+/// # type ProjectionOwned = ();
+/// # trait Dox {
+/// fn project_replace_with(
+///     self: Pin<&mut Self>,
+///     other: Self,
+///     f: impl FnOnce(Projection<'_>),
+/// ) -> ProjectionOwned;
+/// # }
+/// ```
+///
+/// The projection cannot escape the closure, and the fields are dropped in the
+/// same order as `project_replace`. When used on enums, this argument also
+/// requires the `project` argument, so that the closure can match on the
+/// variants of the projection.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project_replace, project_replace_with)]
+/// struct Struct<T> {
+///     #[pin]
+///     pinned_field: T,
+///     unpinned_field: usize,
+/// }
+///
+/// let mut x = Box::pin(Struct { pinned_field: 1, unpinned_field: 2 });
+///
+/// let mut last = 0;
+/// let this =
+///     x.as_mut().project_replace_with(Struct { pinned_field: 3, unpinned_field: 4 }, |this| {
+///         last = *this.pinned_field
+///     });
+/// assert_eq!(last, 1);
+/// assert_eq!(this.unpinned_field, 2);
+/// ```
+///
+/// # `project_transition`
+///
+/// With `project_replace`, the replacement value must be created before the
+/// fields of the old value are available. The `project_transition` argument
+/// generates a method that instead computes the new value from the projected
+/// owned fields of the old value:
+///
+/// ```
+/// # use std::pin::Pin;
+/// # type ProjectionOwned = ();
+/// # trait Dox {
+/// fn project_transition(self: Pin<&mut Self>, f: impl FnOnce(ProjectionOwned) -> Self);
+/// # }
+/// ```
+///
+/// The unpinned fields are moved out, the pinned fields are dropped in place
+/// (after calling `PinnedDrop::drop` if `#[pinned_drop]` is used), and then
+/// the value returned by `f` is written into `self`. This argument requires
+/// the `project_replace` argument.
+///
+/// This is useful for state machines such as the `Map` future, which would
+/// otherwise need a placeholder variant to pass to `project_replace`:
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project = MapProj, project_replace = MapProjOwn, project_transition)]
+/// enum Map<Fut, F, T> {
+///     Incomplete {
+///         #[pin]
+///         future: Fut,
+///         f: F,
+///     },
+///     Complete(T),
+/// }
+///
+/// impl<Fut, F: FnOnce(u8) -> T, T> Map<Fut, F, T> {
+///     fn complete(self: Pin<&mut Self>, output: u8) {
+///         self.project_transition(|this| match this {
+///             MapProjOwn::Incomplete { f, .. } => Map::Complete(f(output)),
+///             MapProjOwn::Complete(value) => Map::Complete(value),
+///         });
+///     }
+/// }
+/// ```
+///
+/// Note that `self` has no valid value while the pinned fields are being
+/// dropped and while `f` is running, so **the process is aborted** if any of
+/// them panics.
+///
+/// # `into_parts`
+///
+/// Types that use the [`#[pinned_drop]`][pinned-drop] attribute have a `Drop`
+/// impl, so their fields cannot be moved out by destructuring, even if the
+/// value has never been pinned. The `into_parts = <ident>` argument generates
+/// a type named `<ident>` with the same fields as the original type, and an
+/// `into_parts` method that moves all the fields, pinned or not, into it
+/// without calling `PinnedDrop::drop`:
+///
+/// ```
+/// # type Parts = ();
+/// # trait Dox {
+/// fn into_parts(self) -> Parts;
+/// # }
+/// ```
+///
+/// This is safe because a value that is passed by value is not pinned.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::{pin_project, pinned_drop};
+///
+/// #[pin_project(PinnedDrop, into_parts = StructParts)]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned_field: T,
+///     unpinned_field: U,
+/// }
+///
+/// #[pinned_drop]
+/// impl<T, U> PinnedDrop for Struct<T, U> {
+///     fn drop(self: Pin<&mut Self>) {
+///         // ...
+///     }
+/// }
+///
+/// let x = Struct { pinned_field: 42, unpinned_field: "hello" };
+/// let StructParts { pinned_field, unpinned_field } = x.into_parts();
+/// assert_eq!(pinned_field, 42);
+/// assert_eq!(unpinned_field, "hello");
+/// ```
+///
+/// Like the `project` argument, `into_parts = _` names the type `{Type}Parts`.
+///
+/// # `accessors`
+///
+/// By passing the `accessors` argument to the `#[pin_project]` attribute on a
+/// struct with named fields, methods that project only one field are generated
+/// in addition to the `project` and `project_ref` methods.
+///
+/// ```
+/// # use std::pin::Pin;
+/// # type Pinned = ();
+/// # type Unpinned = ();
+/// # trait Dox {
+/// // for `#[pin] pinned: Pinned`
+/// fn pinned(self: Pin<&mut Self>) -> Pin<&mut Pinned>;
+/// fn pinned_ref(self: Pin<&Self>) -> Pin<&Pinned>;
+/// fn set_pinned(self: Pin<&mut Self>, value: Pinned);
+/// // for `unpinned: Unpinned`
+/// fn unpinned(self: Pin<&mut Self>) -> &mut Unpinned;
+/// fn unpinned_ref(self: Pin<&Self>) -> &Unpinned;
+/// # }
+/// ```
+///
+/// The `set_<field>` method is equivalent to calling [`Pin::set`] on the
+/// projected field, and is not generated for unpinned fields.
+///
+/// ```
+/// use std::{
+///     future::Future,
+///     pin::Pin,
+///     task::{Context, Poll},
+/// };
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(accessors)]
+/// struct Counted<F> {
+///     #[pin]
+///     fut: F,
+///     count: usize,
+/// }
+///
+/// impl<F: Future> Future for Counted<F> {
+///     type Output = F::Output;
+///
+///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+///         *self.as_mut().count() += 1;
+///         self.fut().poll(cx)
+///     }
+/// }
+/// ```
+///
+/// It is an error if the name of an accessor method is the same as the name
+/// of another accessor method or another generated method such as `project`.
+/// In that case, rename the field, or rename the projection methods with the
+/// [`project_fn`](#project_fn) arguments.
+///
+/// # `project_variants`
+///
+/// By passing the `project_variants` argument to the `#[pin_project]`
+/// attribute on an enum, projected structs and methods for each variant are
+/// generated in addition to the `project` and `project_ref` methods.
+///
+/// ```
+/// # use std::pin::Pin;
+/// # type EnumVariantProj<'a> = &'a ();
+/// # type EnumVariantProjRef<'a> = &'a ();
+/// # trait Dox {
+/// // for `Enum::Variant`
+/// fn is_variant(&self) -> bool;
+/// fn project_variant(self: Pin<&mut Self>) -> Option<EnumVariantProj<'_>>;
+/// fn project_variant_ref(self: Pin<&Self>) -> Option<EnumVariantProjRef<'_>>;
+/// # }
+/// ```
+///
+/// The names of the methods use the snake case name of the variant, and the
+/// projected structs are named `{Type}{Variant}Proj` and
+/// `{Type}{Variant}ProjRef`. The projected structs have the same fields as the
+/// variant, and a hidden field, so use `..` when destructuring them.
+///
+/// It is an error if these names are the same as the names generated for
+/// another variant (e.g., `project_a_ref` for both `A` and `ARef` variants),
+/// or the names of the other generated methods and projected types (e.g.,
+/// `project_ref` for `Ref` variant). In that case, rename the variant.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project_variants)]
+/// enum State<F, T> {
+///     Pending {
+///         #[pin]
+///         fut: F,
+///     },
+///     Done(T),
+/// }
+///
+/// fn poll_pending<F, T>(this: StatePendingProj<'_, F, T>) -> Pin<&mut F> {
+///     this.fut
+/// }
+///
+/// impl<F, T> State<F, T> {
+///     fn method(self: Pin<&mut Self>) {
+///         if let Some(pending) = self.project_pending() {
+///             let _: Pin<&mut F> = poll_pending(pending);
+///         }
+///     }
+/// }
+/// ```
+///
+/// With `project_variants`, a variant can also declare a drop hook with the
+/// `#[pin(drop = <path>)]` attribute. When the value is dropped while that
+/// variant is active, the hook is called with the per-variant projected type,
+/// before the fields are dropped. As with `PinnedDrop`, the hook is also called
+/// when the value is replaced by `project_replace` and the related methods.
+/// Nothing is called for variants without a hook. This is an alternative to
+/// `PinnedDrop` for enums, and cannot be used together with it.
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project_variants)]
+/// enum State<F> {
+///     #[pin(drop = Self::cancel)]
+///     Running {
+///         #[pin]
+///         fut: F,
+///         id: usize,
+///     },
+///     Done,
+/// }
+///
+/// impl<F> State<F> {
+///     fn cancel(this: StateRunningProj<'_, F>) {
+///         println!("canceling task {}", this.id);
+///     }
+/// }
+/// ```
+///
+/// # `PinProject`
+///
+/// The `PinProject` argument implements the [`PinProject`] trait for the
+/// original type, which provides the same projections as the `project` and
+/// `project_ref` methods, so that they can be used in code that is generic
+/// over pin-projectable types.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::{PinProject, pin_project};
+///
+/// #[pin_project(PinProject)]
+/// pub struct Struct<T> {
+///     #[pin]
+///     pinned: T,
+/// }
+///
+/// fn pinned<T: PinProject>(x: Pin<&mut T>) -> T::Proj<'_> {
+///     x.project()
+/// }
+/// ```
+///
+/// The projected types are used in the trait impl, so they must be as visible
+/// as the original type. The unnamed projected types have the same visibility
+/// as the original type in that case, but are still not nameable by user code.
+/// The visibility of the named projected types is not changed, so if the
+/// original type is `pub`, the [`project_vis`](#project_vis) argument is also
+/// required:
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(PinProject, project = StructProj, project_vis = pub)]
+/// pub struct Struct<T> {
+///     #[pin]
+///     pinned: T,
+/// }
+/// ```
+///
+/// This argument cannot be used with the `!project` and `!project_ref`
+/// arguments.
+///
+/// # `#[pin(project)]`
+///
+/// If the type of a pinned field is also a type with `#[pin_project]`
+/// attribute and `PinProject` argument (or, more generally, implements the
+/// [`PinProject`] trait), you can use `#[pin(project)]` instead of `#[pin]`
+/// to project through it. The field is then projected to the projected type
+/// of the field type, instead of [`Pin`]`<&mut Field>`.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(PinProject)]
+/// struct Inner<T> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: usize,
+/// }
+///
+/// #[pin_project]
+/// struct Outer<T> {
+///     #[pin(project)]
+///     inner: Inner<T>,
+/// }
+///
+/// impl<T> Outer<T> {
+///     fn method(self: Pin<&mut Self>) {
+///         let this = self.project();
+///         let _: Pin<&mut T> = this.inner.pinned; // Pinned reference to the field
+///         let _: &mut usize = this.inner.unpinned; // Normal reference to the field
+///     }
+/// }
+/// ```
+///
+/// A `#[pin(project)]` field is treated as a pinned field in every other
+/// respect, e.g., it is included in the conditions of the `Unpin` impl and
+/// is replaced by [`PhantomData`] in the type returned by `project_replace`.
+///
+/// # `#[pin(elements)]`
+///
+/// The elements of a pinned array or slice are structurally pinned. For a field
+/// of an array type `[T; N]` or a slice type `[T]`, you can use
+/// `#[pin(elements)]` instead of `#[pin]` to get pinned references to each
+/// element. The field is then projected to [`PinSliceMut`]`<'_, T>` (or
+/// [`PinSliceRef`]`<'_, T>` by `project_ref`), instead of
+/// [`Pin`]`<&mut [T; N]>`, which provides indexing and iterators over
+/// [`Pin`]`<&mut T>`.
+///
+/// ```
+/// use std::{future::Future, pin::Pin};
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project]
+/// struct JoinAll<F, const N: usize> {
+///     #[pin(elements)]
+///     futures: [F; N],
+/// }
+///
+/// impl<F: Future, const N: usize> JoinAll<F, N> {
+///     fn method(self: Pin<&mut Self>) {
+///         let this = self.project();
+///         for future in this.futures {
+///             let _: Pin<&mut F> = future; // Pinned reference to the element
+///         }
+///     }
+/// }
+/// ```
+///
+/// A `#[pin(elements)]` field is treated as a pinned field in every other
+/// respect, like a `#[pin(project)]` field.
+///
+/// # `#[pin(skip)]`
+///
+/// Fields with `#[pin(skip)]` attribute are omitted from the projected types
+/// returned by the `project`, `project_ref`, and `project_replace` methods.
+/// This is useful for fields such as [`PhantomData`] markers that never need
+/// to be accessed via projections.
+///
+/// ```
+/// use std::{marker::PhantomData, pin::Pin};
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project = StructProj)]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned: T,
+///     #[pin(skip)]
+///     marker: PhantomData<U>,
+/// }
+///
+/// impl<T, U> Struct<T, U> {
+///     fn method(self: Pin<&mut Self>) {
+///         let StructProj { pinned, .. } = self.project();
+///         let _: Pin<&mut T> = pinned;
+///     }
+/// }
+/// ```
+///
+/// The projected types have a private hidden field in place of the skipped
+/// fields, so use `..` when destructuring them. In tuple structs and tuple
+/// variants, each skipped field is replaced by a hidden field at the same
+/// position, so the other fields keep their indices.
+///
+/// To skip a pinned field, use `#[pin]` and `#[pin(skip)]` together. Such a
+/// field is still included in the conditions of the `Unpin` impl. Skipped
+/// fields are dropped in place by the `project_replace` method, regardless of
+/// whether they are pinned.
+///
+/// # `#[pin(unsafe_exclude_from_unpin)]`
+///
+/// A field with `#[pin(unsafe_exclude_from_unpin)]` attribute is pinned like a
+/// field with `#[pin]` attribute, but it is not included in the conditions of
+/// the automatically generated [`Unpin`] impl. The other pinned fields are
+/// still included.
+///
+/// ```
+/// use std::marker::PhantomPinned;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project]
+/// struct Struct<T> {
+///     #[pin(unsafe_exclude_from_unpin)]
+///     excluded: PhantomPinned,
+///     #[pin]
+///     pinned: T,
+/// }
+/// ```
+///
+/// This attribute is unsafe to use, and is treated as unsafe code by the
+/// `unsafe_code` lint. If the type implements [`Unpin`], the pinned value can
+/// be unpinned with [`Pin::get_mut`], and the excluded field can be moved even
+/// after a [`Pin`] pointing to it has been returned by the projection.
+/// You must ensure that this is sound for the field, in the same way as
+/// implementing [`UnsafeUnpin`].
+///
+/// This attribute cannot be used together with the `UnsafeUnpin` or `!Unpin`
+/// argument.
+///
+/// # `project_vis`
+///
+/// To use the projected types and the projection methods of a `pub` type from
+/// other crates, pass the desired visibility using the `project_vis` argument.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project = StructProj, project_vis = pub)]
+/// pub struct Struct<T, U> {
+///     #[pin]
+///     pub pinned: T,
+///     pub unpinned: U,
+/// }
+///
+/// pub fn method<T, U>(this: Pin<&mut Struct<T, U>>) -> StructProj<'_, T, U> {
+///     this.project()
+/// }
+/// ```
+///
+/// The fields of the projected types keep the visibility of the corresponding
+/// fields of the original type.
+///
+/// # `project_fn`
+///
+/// The projection methods can be renamed using the `project_fn = <ident>`,
+/// `project_ref_fn = <ident>`, and `project_replace_fn = <ident>` arguments.
+/// This is useful when the original type already has a method named `project`.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project_fn = pin_mut_fields, project_ref_fn = pin_ref_fields)]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: U,
+/// }
+///
+/// impl<T, U> Struct<T, U> {
+///     fn method(self: Pin<&mut Self>) {
+///         let this = self.pin_mut_fields();
+///         let _: Pin<&mut T> = this.pinned;
+///         let _: &mut U = this.unpinned;
+///     }
+/// }
+/// ```
+///
+/// The `project` and `project_ref` methods can also be omitted using the
+/// `!project` and `!project_ref` arguments.
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(!project_ref)]
+/// struct Struct<T> {
+///     #[pin]
+///     pinned: T,
+/// }
+/// ```
+///
+/// # `project_attr`
+///
+/// To add attributes to the projected types, use the `project_attr(...)`,
+/// `project_ref_attr(...)`, and `project_replace_attr(...)` arguments. The
+/// attributes are added to the types returned by the `project`, `project_ref`,
+/// and `project_replace` methods, respectively.
+///
+/// ```
+/// use std::{marker::PhantomData, pin::Pin};
+///
+/// use pin_project::pin_project;
+///
+/// #[pin_project(
+///     project = StructProj,
+///     project_replace = StructProjOwn,
+///     project_attr(must_use),
+///     project_replace_attr(derive(Debug, PartialEq)),
+/// )]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: U,
+/// }
+///
+/// let mut x = Struct { pinned: 1, unpinned: 2 };
+/// let old = Pin::new(&mut x).project_replace(Struct { pinned: 3, unpinned: 4 });
+/// assert_eq!(old, StructProjOwn { pinned: PhantomData, unpinned: 2 });
+/// ```
+///
+/// The same arguments can be used in the `#[pin(...)]` attribute on fields and
+/// enum variants to add attributes to the corresponding fields and variants of
+/// the projected types.
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(project = EnumProj)]
+/// enum Enum<T> {
+///     #[pin(project_attr(non_exhaustive))]
+///     Variant {
+///         #[pin]
+///         #[pin(project_attr(doc(hidden)))]
+///         pinned: T,
+///     },
+/// }
+/// ```
+///
+/// # `crate`
+///
+/// By default, the code generated by `#[pin_project]` refers to this crate
+/// as `::pin_project`. If pin-project is renamed in `Cargo.toml` or is used
+/// through a re-export from another crate, pass the path to it using the
+/// `crate` argument.
+///
+/// ```
+/// mod facade {
+///     pub use pin_project;
+/// }
+///
+/// use facade::pin_project::pin_project;
+///
+/// #[pin_project(crate = crate::facade::pin_project)]
+/// struct Struct<T, U> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: U,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The same argument is also accepted by the
+/// [`#[pinned_drop]`][macro@pinned_drop] attribute.
+///
 /// [`PhantomData`]: core::marker::PhantomData
 /// [`PhantomPinned`]: core::marker::PhantomPinned
+/// [`PinProject`]: https://docs.rs/pin-project/latest/pin_project/trait.PinProject.html
+/// [`PinSliceMut`]: https://docs.rs/pin-project/latest/pin_project/slice/struct.PinSliceMut.html
+/// [`PinSliceRef`]: https://docs.rs/pin-project/latest/pin_project/slice/struct.PinSliceRef.html
 /// [`Pin::as_mut`]: core::pin::Pin::as_mut
+/// [`Pin::get_mut`]: core::pin::Pin::get_mut
 /// [`Pin::set`]: core::pin::Pin::set
 /// [`Pin`]: core::pin::Pin
 /// [`UnsafeUnpin`]: https://docs.rs/pin-project/latest/pin_project/trait.UnsafeUnpin.html
@@ -544,6 +1297,10 @@ pub fn pin_project(args: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// See also ["pinned-drop" section of `#[pin_project]` attribute][pinned-drop].
 ///
+/// If pin-project is renamed or re-exported, pass the path to it using the
+/// `crate` argument, in the same way as the
+/// [`#[pin_project]`][macro@pin_project] attribute: `#[pinned_drop(crate = <path>)]`.
+///
 /// # Why `#[pinned_drop]` attribute is needed?
 ///
 /// Implementing `PinnedDrop::drop` is safe, but calling it is not safe.
@@ -1,15 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::quote;
 use syn::{
-    Attribute, Error, Ident, Result, Token,
-    parse::{Parse, ParseStream},
+    Attribute, Error, Ident, Meta, Path, Result, Token, Visibility, WhereClause,
+    parse::{Parse, ParseStream, Parser as _},
+    punctuated::Punctuated,
     spanned::Spanned as _,
+    token,
 };
 
 use super::PIN;
-use crate::utils::{ParseBufferExt as _, SliceExt as _};
+use crate::utils::{ParseBufferExt as _, SliceExt as _, parse_crate_path};
 
 pub(super) fn parse_args(attrs: &[Attribute]) -> Result<Args> {
     // `(__private(<args>))` -> `<args>`
@@ -58,30 +60,95 @@ pub(super) fn parse_args(attrs: &[Attribute]) -> Result<Args> {
     syn::parse2(prev.1.unwrap())
 }
 
+/// Finds `crate = <path>` argument from the arguments of `#[pin_project]`
+/// attribute.
+///
+/// This does not validate the arguments. Invalid arguments are reported when
+/// the arguments are parsed by `parse_args`.
+pub(super) fn find_crate_path(args: &TokenStream) -> Option<Path> {
+    fn is_comma(token: &TokenTree) -> bool {
+        matches!(token, TokenTree::Punct(p) if p.as_char() == ',')
+    }
+
+    let mut tokens = args.clone().into_iter();
+    loop {
+        let arg: Vec<_> = tokens.by_ref().take_while(|t| !is_comma(t)).collect();
+        match &*arg {
+            [] => return None,
+            [TokenTree::Ident(name), TokenTree::Punct(eq), path @ ..]
+                if name == "crate" && eq.as_char() == '=' =>
+            {
+                return Path::parse_mod_style.parse2(path.iter().cloned().collect()).ok();
+            }
+            _ => {}
+        }
+    }
+}
+
 pub(super) struct Args {
-    /// `PinnedDrop` argument.
+    /// `PinnedDrop [= <path>]` argument.
     pub(super) pinned_drop: Option<Span>,
+    /// `<path>` in `PinnedDrop = <path>` argument.
+    pub(super) pinned_drop_fn: Option<Path>,
     /// `UnsafeUnpin` or `!Unpin` argument.
     pub(super) unpin_impl: UnpinImpl,
+    /// `Unpin where <predicates>` argument.
+    pub(super) unpin_bounds: Option<WhereClause>,
+    /// `negative_impl` argument.
+    pub(super) negative_impl: Option<Span>,
+    /// `PinProject` argument.
+    pub(super) pin_project: Option<Span>,
     /// `project = <ident>` argument.
+    ///
+    /// `_` means that the name is inferred from the original type name.
     pub(super) project: Option<Ident>,
     /// `project_ref = <ident>` argument.
+    ///
+    /// `_` means that the name is inferred from the original type name.
     pub(super) project_ref: Option<Ident>,
     /// `project_replace [= <ident>]` argument.
+    ///
+    /// `_` means that the name is inferred from the original type name.
     pub(super) project_replace: ProjReplace,
+    /// `project_fn = <ident>` or `!project` argument.
+    pub(super) project_fn: ProjFn,
+    /// `project_ref_fn = <ident>` or `!project_ref` argument.
+    pub(super) project_ref_fn: ProjFn,
+    /// `project_replace_fn = <ident>` argument.
+    pub(super) project_replace_fn: Option<Ident>,
+    /// `project_take` argument.
+    pub(super) project_take: Option<Span>,
+    /// `project_replace_with` argument.
+    pub(super) project_replace_with: Option<Span>,
+    /// `project_transition` argument.
+    pub(super) project_transition: Option<Span>,
+    /// `into_parts = <ident>` argument.
+    ///
+    /// `_` means that the name is inferred from the original type name.
+    pub(super) into_parts: Option<Ident>,
+    /// `accessors` argument.
+    pub(super) accessors: Option<Span>,
+    /// `project_variants` argument.
+    pub(super) project_variants: Option<Span>,
+    /// `project_vis = <visibility>` argument.
+    pub(super) project_vis: Option<Visibility>,
+    /// `project_attr(...)`, `project_ref_attr(...)`, and
+    /// `project_replace_attr(...)` arguments.
+    pub(super) proj_attrs: ProjAttrs,
+    /// `crate = <path>` argument.
+    pub(super) crate_path: Option<Path>,
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        mod kw {
-            syn::custom_keyword!(Unpin);
-        }
-
         /// Parses `= <value>` in `<name> = <value>` and returns value and span of name-value pair.
+        ///
+        /// If `allow_infer` is `true`, `_` is also accepted as a value.
         fn parse_value(
             input: ParseStream<'_>,
             name: &Ident,
             has_prev: bool,
+            allow_infer: bool,
         ) -> Result<(Ident, TokenStream)> {
             if input.is_empty() {
                 bail!(name, "expected `{0} = <identifier>`, found `{0}`", name);
@@ -91,7 +158,12 @@ impl Parse for Args {
                 let span = quote!(#name #eq_token);
                 bail!(span, "expected `{0} = <identifier>`, found `{0} =`", name);
             }
-            let value: Ident = input.parse()?;
+            let value = if allow_infer && input.peek(Token![_]) {
+                let underscore: Token![_] = input.parse()?;
+                Ident::new("_", underscore.span)
+            } else {
+                input.parse()?
+            };
             let span = quote!(#name #value);
             if has_prev {
                 bail!(span, "duplicate `{}` argument", name);
@@ -100,12 +172,30 @@ impl Parse for Args {
         }
 
         let mut pinned_drop = None;
+        let mut pinned_drop_fn = None;
         let mut unsafe_unpin = None;
         let mut not_unpin = None;
+        let mut unpin_bounds = None;
+        let mut negative_impl = None;
+        let mut pin_project = None;
         let mut project = None;
         let mut project_ref = None;
         let mut project_replace_value = None;
         let mut project_replace_span = None;
+        let mut project_fn = None;
+        let mut project_ref_fn = None;
+        let mut project_replace_fn = None;
+        let mut no_project = None;
+        let mut no_project_ref = None;
+        let mut project_take = None;
+        let mut project_replace_with = None;
+        let mut project_transition = None;
+        let mut into_parts = None;
+        let mut accessors = None;
+        let mut project_variants = None;
+        let mut project_vis = None;
+        let mut proj_attrs = ProjAttrs::default();
+        let mut crate_path = None;
 
         while !input.is_empty() {
             if input.peek(Token![!]) {
@@ -113,11 +203,20 @@ impl Parse for Args {
                 if input.is_empty() {
                     bail!(bang, "expected `!Unpin`, found `!`");
                 }
-                let unpin: kw::Unpin = input.parse()?;
-                let span = quote!(#bang #unpin);
-                if not_unpin.replace(span.span()).is_some() {
-                    bail!(span, "duplicate `!Unpin` argument");
+                let token: Ident = input.parse()?;
+                let span = quote!(#bang #token);
+                let prev = match &*token.to_string() {
+                    "Unpin" => &mut not_unpin,
+                    "project" => &mut no_project,
+                    "project_ref" => &mut no_project_ref,
+                    _ => bail!(token, "expected `Unpin`, `project`, or `project_ref`"),
+                };
+                if prev.replace(span.span()).is_some() {
+                    bail!(span, "duplicate `!{}` argument", token);
                 }
+            } else if input.peek(Token![crate]) {
+                let crate_token: Token![crate] = input.parse()?;
+                crate_path = Some(parse_crate_path(input, crate_token, crate_path.is_some())?);
             } else {
                 let token = input.parse::<Ident>()?;
                 match &*token.to_string() {
@@ -125,22 +224,49 @@ impl Parse for Args {
                         if pinned_drop.replace(token.span()).is_some() {
                             bail!(token, "duplicate `PinnedDrop` argument");
                         }
+                        if input.peek(Token![=]) {
+                            let eq_token: Token![=] = input.parse()?;
+                            if input.is_empty() || input.peek(Token![,]) {
+                                let span = quote!(#token #eq_token);
+                                bail!(span, "expected `PinnedDrop = <path>`, found `PinnedDrop =`");
+                            }
+                            pinned_drop_fn = Some(input.parse()?);
+                        }
+                    }
+                    "Unpin" if input.peek(Token![where]) => {
+                        let where_token: Token![where] = input.parse()?;
+                        if input.is_empty() {
+                            let span = quote!(#token #where_token);
+                            bail!(span, "expected `Unpin where <predicates>`, found `Unpin where`");
+                        }
+                        // Where predicates may contain commas, so this
+                        // argument consumes all remaining input.
+                        unpin_bounds = Some(WhereClause {
+                            where_token,
+                            predicates: Punctuated::parse_terminated(input)?,
+                        });
                     }
                     "UnsafeUnpin" => {
                         if unsafe_unpin.replace(token.span()).is_some() {
                             bail!(token, "duplicate `UnsafeUnpin` argument");
                         }
                     }
+                    "PinProject" => {
+                        if pin_project.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `PinProject` argument");
+                        }
+                    }
                     "project" => {
-                        project = Some(parse_value(input, &token, project.is_some())?.0);
+                        project = Some(parse_value(input, &token, project.is_some(), true)?.0);
                     }
                     "project_ref" => {
-                        project_ref = Some(parse_value(input, &token, project_ref.is_some())?.0);
+                        project_ref =
+                            Some(parse_value(input, &token, project_ref.is_some(), true)?.0);
                     }
                     "project_replace" => {
                         if input.peek(Token![=]) {
                             let (value, span) =
-                                parse_value(input, &token, project_replace_span.is_some())?;
+                                parse_value(input, &token, project_replace_span.is_some(), true)?;
                             project_replace_value = Some(value);
                             project_replace_span = Some(span.span());
                         } else if project_replace_span.is_some() {
@@ -149,12 +275,84 @@ impl Parse for Args {
                             project_replace_span = Some(token.span());
                         }
                     }
+                    "project_fn" => {
+                        project_fn =
+                            Some(parse_value(input, &token, project_fn.is_some(), false)?.0);
+                    }
+                    "project_ref_fn" => {
+                        project_ref_fn =
+                            Some(parse_value(input, &token, project_ref_fn.is_some(), false)?.0);
+                    }
+                    "project_replace_fn" => {
+                        project_replace_fn = Some(
+                            parse_value(input, &token, project_replace_fn.is_some(), false)?.0,
+                        );
+                    }
+                    "negative_impl" => {
+                        if negative_impl.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `negative_impl` argument");
+                        }
+                    }
+                    "project_take" => {
+                        if project_take.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `project_take` argument");
+                        }
+                    }
+                    "project_replace_with" => {
+                        if project_replace_with.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `project_replace_with` argument");
+                        }
+                    }
+                    "project_transition" => {
+                        if project_transition.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `project_transition` argument");
+                        }
+                    }
+                    "into_parts" => {
+                        into_parts =
+                            Some(parse_value(input, &token, into_parts.is_some(), true)?.0);
+                    }
+                    "accessors" => {
+                        if accessors.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `accessors` argument");
+                        }
+                    }
+                    "project_variants" => {
+                        if project_variants.replace(token.span()).is_some() {
+                            bail!(token, "duplicate `project_variants` argument");
+                        }
+                    }
+                    "project_vis" => {
+                        if input.is_empty() {
+                            bail!(
+                                token,
+                                "expected `project_vis = <visibility>`, found `project_vis`"
+                            );
+                        }
+                        let eq_token: Token![=] = input.parse()?;
+                        if input.is_empty() {
+                            let span = quote!(#token #eq_token);
+                            bail!(
+                                span,
+                                "expected `project_vis = <visibility>`, found `project_vis =`"
+                            );
+                        }
+                        if !input.peek(Token![pub]) {
+                            return Err(input.error("expected visibility"));
+                        }
+                        let vis: Visibility = input.parse()?;
+                        if project_vis.is_some() {
+                            bail!(quote!(#token #vis), "duplicate `project_vis` argument");
+                        }
+                        project_vis = Some(vis);
+                    }
                     "Replace" => {
                         bail!(
                             token,
                             "`Replace` argument was removed, use `project_replace` argument instead"
                         );
                     }
+                    _ if proj_attrs.parse_arg(&token, input)? => {}
                     _ => bail!(token, "unexpected argument: {}", token),
                 }
             }
@@ -165,31 +363,116 @@ impl Parse for Args {
             let _: Token![,] = input.parse()?;
         }
 
-        if project.is_some() || project_ref.is_some() {
-            if project == project_ref {
+        // `_` is replaced with the inferred name later, which never conflicts
+        // with the other inferred names.
+        let explicit = |ident: &Option<Ident>| ident.clone().filter(|ident| ident != "_");
+        let (project_name, project_ref_name) = (explicit(&project), explicit(&project_ref));
+        if project_name.is_some() || project_ref_name.is_some() {
+            if project_name == project_ref_name {
                 bail!(
-                    project_ref,
+                    project_ref_name,
                     "name `{}` is already specified by `project` argument",
-                    project_ref.as_ref().unwrap()
+                    project_ref_name.as_ref().unwrap()
                 );
             }
-            if let Some(ident) = &project_replace_value {
-                if project == project_replace_value {
+            if let Some(ident) = &explicit(&project_replace_value) {
+                if project_name.as_ref() == Some(ident) {
                     bail!(ident, "name `{}` is already specified by `project` argument", ident);
-                } else if project_ref == project_replace_value {
+                } else if project_ref_name.as_ref() == Some(ident) {
                     bail!(ident, "name `{}` is already specified by `project_ref` argument", ident);
                 }
             }
         }
 
-        if let Some(span) = pinned_drop {
-            if project_replace_span.is_some() {
-                return Err(Error::new(
-                    span,
-                    "arguments `PinnedDrop` and `project_replace` are mutually exclusive",
-                ));
+        if let Some(ident) = &explicit(&into_parts) {
+            for (name, arg) in [
+                (project_name.as_ref(), "project"),
+                (project_ref_name.as_ref(), "project_ref"),
+                (explicit(&project_replace_value).as_ref(), "project_replace"),
+            ] {
+                if name == Some(ident) {
+                    bail!(ident, "name `{}` is already specified by `{}` argument", ident, arg);
+                }
+            }
+        }
+
+        if let Some(ident) = &project_ref_fn {
+            if project_fn.as_ref() == Some(ident) {
+                bail!(ident, "name `{}` is already specified by `project_fn` argument", ident);
+            }
+        }
+        if let Some(ident) = &project_replace_fn {
+            if project_fn.as_ref() == Some(ident) {
+                bail!(ident, "name `{}` is already specified by `project_fn` argument", ident);
+            } else if project_ref_fn.as_ref() == Some(ident) {
+                bail!(ident, "name `{}` is already specified by `project_ref_fn` argument", ident);
             }
+            if project_replace_span.is_none() {
+                bail!(ident, "`project_replace_fn` argument requires `project_replace` argument");
+            }
+        }
+        // The methods generated by these arguments have fixed names.
+        for ident in [&project_fn, &project_ref_fn, &project_replace_fn].into_iter().flatten() {
+            for (span, arg) in [
+                (project_take, "project_take"),
+                (project_replace_with, "project_replace_with"),
+                (project_transition, "project_transition"),
+            ] {
+                if span.is_some() && ident == arg {
+                    bail!(
+                        ident,
+                        "name `{}` is already used by the method generated by `{}` argument",
+                        ident,
+                        arg
+                    );
+                }
+            }
+        }
+        if let (Some(span), None) = (project_take, project_replace_span) {
+            return Err(Error::new(
+                span,
+                "`project_take` argument requires `project_replace` argument",
+            ));
+        }
+        if let (Some(span), None) = (project_replace_with, project_replace_span) {
+            return Err(Error::new(
+                span,
+                "`project_replace_with` argument requires `project_replace` argument",
+            ));
+        }
+        if let (Some(span), None) = (project_transition, project_replace_span) {
+            return Err(Error::new(
+                span,
+                "`project_transition` argument requires `project_replace` argument",
+            ));
+        }
+
+        let project_fn = ProjFn::new(project_fn, no_project, "project")?;
+        let project_ref_fn = ProjFn::new(project_ref_fn, no_project_ref, "project_ref")?;
+        if let (ProjFn::Suppressed(span), Some(_)) = (&project_fn, &project) {
+            return Err(Error::new(
+                *span,
+                "arguments `!project` and `project = <identifier>` are mutually exclusive",
+            ));
         }
+        if let (ProjFn::Suppressed(span), Some(_)) = (&project_ref_fn, &project_ref) {
+            return Err(Error::new(
+                *span,
+                "arguments `!project_ref` and `project_ref = <identifier>` are mutually exclusive",
+            ));
+        }
+
+        if let Some(span) = pin_project {
+            for (no_project, arg) in [(no_project, "!project"), (no_project_ref, "!project_ref")] {
+                if no_project.is_some() {
+                    return Err(Error::new(
+                        span,
+                        format!("arguments `PinProject` and `{arg}` are mutually exclusive"),
+                    ));
+                }
+            }
+        }
+
         let project_replace = match (project_replace_span, project_replace_value) {
             (None, _) => ProjReplace::None,
             (Some(span), Some(ident)) => ProjReplace::Named { ident, span },
@@ -206,8 +489,262 @@ impl Parse for Args {
                 ));
             }
         };
+        let unpin_arg = match unpin_impl {
+            UnpinImpl::Default => None,
+            UnpinImpl::Unsafe(span) => Some((span, "UnsafeUnpin")),
+            UnpinImpl::Negative(span) => Some((span, "!Unpin")),
+        };
+        if let Some(span) = negative_impl {
+            if !matches!(unpin_impl, UnpinImpl::Negative(_)) {
+                return Err(Error::new(
+                    span,
+                    "`negative_impl` argument requires `!Unpin` argument",
+                ));
+            }
+        }
+        if let (Some((span, arg)), Some(_)) = (unpin_arg, &unpin_bounds) {
+            return Err(Error::new(
+                span,
+                format!("arguments `{arg}` and `Unpin where` are mutually exclusive"),
+            ));
+        }
 
-        Ok(Self { pinned_drop, unpin_impl, project, project_ref, project_replace })
+        Ok(Self {
+            pinned_drop,
+            pinned_drop_fn,
+            unpin_impl,
+            unpin_bounds,
+            negative_impl,
+            pin_project,
+            project,
+            project_ref,
+            project_replace,
+            project_fn,
+            project_ref_fn,
+            project_replace_fn,
+            project_take,
+            project_replace_with,
+            project_transition,
+            into_parts,
+            accessors,
+            project_variants,
+            project_vis,
+            proj_attrs,
+            crate_path,
+        })
+    }
+}
+
+/// Parses the `#[pin]` attribute on a field.
+pub(super) fn parse_field_args(attrs: &[Attribute]) -> Result<FieldArgs> {
+    // A field can have one `#[pin]` and one `#[pin(<args>)]` attribute.
+    let mut pinned = None;
+    let mut args: Option<FieldArgs> = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(PIN)) {
+        match &attr.meta {
+            Meta::List(list) if !list.tokens.is_empty() => {
+                if args.replace(list.parse_args()?).is_some() {
+                    bail!(attr, "duplicate #[pin] attribute");
+                }
+            }
+            meta => {
+                meta.require_path_only()?;
+                if pinned.replace(attr).is_some() {
+                    bail!(attr, "duplicate #[pin] attribute");
+                }
+            }
+        }
+    }
+
+    let mut args = args.unwrap_or_default();
+    args.pinned |= pinned.is_some();
+    Ok(args)
+}
+
+#[derive(Default)]
+pub(super) struct FieldArgs {
+    /// `#[pin]` attribute or an argument that implies it.
+    pub(super) pinned: bool,
+    /// `project` argument.
+    pub(super) project: Option<Span>,
+    /// `elements` argument.
+    pub(super) elements: Option<Span>,
+    /// `skip` argument.
+    pub(super) skip: Option<Span>,
+    /// `unsafe_exclude_from_unpin` argument.
+    pub(super) unsafe_exclude_from_unpin: Option<Span>,
+    /// `project_attr(...)`, `project_ref_attr(...)`, and
+    /// `project_replace_attr(...)` arguments.
+    pub(super) proj_attrs: ProjAttrs,
+}
+
+impl Parse for FieldArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut project = None;
+        let mut elements = None;
+        let mut skip = None;
+        let mut unsafe_exclude_from_unpin = None;
+        let mut proj_attrs = ProjAttrs::default();
+        let mut first_attr_arg = None;
+
+        while !input.is_empty() {
+            let token = input.parse::<Ident>()?;
+            match &*token.to_string() {
+                "project" => {
+                    if project.replace(token.span()).is_some() {
+                        bail!(token, "duplicate `project` argument");
+                    }
+                }
+                "elements" => {
+                    if elements.replace(token.span()).is_some() {
+                        bail!(token, "duplicate `elements` argument");
+                    }
+                }
+                "skip" => {
+                    if skip.replace(token.span()).is_some() {
+                        bail!(token, "duplicate `skip` argument");
+                    }
+                }
+                "unsafe_exclude_from_unpin" => {
+                    if unsafe_exclude_from_unpin.replace(token.span()).is_some() {
+                        bail!(token, "duplicate `unsafe_exclude_from_unpin` argument");
+                    }
+                }
+                _ if proj_attrs.parse_arg(&token, input)? => {
+                    first_attr_arg.get_or_insert(token);
+                }
+                _ => bail!(token, "unexpected argument: {}", token),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        if let (Some(span), Some(_)) = (skip, project) {
+            return Err(Error::new(span, "arguments `skip` and `project` are mutually exclusive"));
+        }
+        if let (Some(span), Some(_)) = (skip, elements) {
+            return Err(Error::new(span, "arguments `skip` and `elements` are mutually exclusive"));
+        }
+        if let (Some(span), Some(_)) = (elements, project) {
+            return Err(Error::new(
+                span,
+                "arguments `elements` and `project` are mutually exclusive",
+            ));
+        }
+        if let (Some(span), Some(name)) = (skip, first_attr_arg) {
+            return Err(Error::new(
+                span,
+                format!("arguments `skip` and `{name}` are mutually exclusive"),
+            ));
+        }
+
+        Ok(Self {
+            pinned: project.is_some() || elements.is_some() || unsafe_exclude_from_unpin.is_some(),
+            project,
+            elements,
+            skip,
+            unsafe_exclude_from_unpin,
+            proj_attrs,
+        })
+    }
+}
+
+#[derive(Default)]
+pub(super) struct VariantArgs {
+    /// `drop = <path>` argument.
+    pub(super) drop: Option<Path>,
+    /// `project_attr(...)`, `project_ref_attr(...)`, and
+    /// `project_replace_attr(...)` arguments.
+    pub(super) proj_attrs: ProjAttrs,
+}
+
+/// Parses the `#[pin(<args>)]` attribute on a variant.
+pub(super) fn parse_variant_args(attrs: &[Attribute]) -> Result<VariantArgs> {
+    let mut args = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(PIN)) {
+        match &attr.meta {
+            Meta::List(list) if !list.tokens.is_empty() => {
+                if args.replace(list.parse_args_with(parse_variant_args_inner)?).is_some() {
+                    bail!(attr, "duplicate #[pin] attribute");
+                }
+            }
+            _ => bail!(attr, "#[pin] attribute may only be used on fields of structs or variants"),
+        }
+    }
+    Ok(args.unwrap_or_default())
+}
+
+fn parse_variant_args_inner(input: ParseStream<'_>) -> Result<VariantArgs> {
+    let mut drop = None;
+    let mut proj_attrs = ProjAttrs::default();
+    while !input.is_empty() {
+        let token = input.parse::<Ident>()?;
+        match &*token.to_string() {
+            "drop" => {
+                if input.is_empty() || input.peek(Token![,]) {
+                    bail!(token, "expected `drop = <path>`, found `drop`");
+                }
+                let eq_token: Token![=] = input.parse()?;
+                if input.is_empty() || input.peek(Token![,]) {
+                    let span = quote!(#token #eq_token);
+                    bail!(span, "expected `drop = <path>`, found `drop =`");
+                }
+                let path: Path = input.parse()?;
+                if drop.is_some() {
+                    let span = quote!(#token #path);
+                    bail!(span, "duplicate `drop` argument");
+                }
+                drop = Some(path);
+            }
+            _ if proj_attrs.parse_arg(&token, input)? => {}
+            _ => bail!(token, "unexpected argument: {}", token),
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        let _: Token![,] = input.parse()?;
+    }
+    Ok(VariantArgs { drop, proj_attrs })
+}
+
+/// Attributes forwarded onto the projected types by `project_attr(...)`,
+/// `project_ref_attr(...)`, and `project_replace_attr(...)` arguments.
+#[derive(Default)]
+pub(super) struct ProjAttrs {
+    /// Attributes for the projected type returned by `project` method.
+    project: Vec<Meta>,
+    /// Attributes for the projected type returned by `project_ref` method.
+    project_ref: Vec<Meta>,
+    /// Attributes for the projected type returned by `project_replace` method.
+    project_replace: Vec<Meta>,
+}
+
+impl ProjAttrs {
+    /// Parses `(<attr>, ...)` in `<name>(<attr>, ...)` and returns `true` if
+    /// `name` is one of the arguments that forward attributes.
+    fn parse_arg(&mut self, name: &Ident, input: ParseStream<'_>) -> Result<bool> {
+        let attrs = match &*name.to_string() {
+            "project_attr" => &mut self.project,
+            "project_ref_attr" => &mut self.project_ref,
+            "project_replace_attr" => &mut self.project_replace,
+            _ => return Ok(false),
+        };
+        if !input.peek(token::Paren) {
+            bail!(name, "expected `{0}(<attribute>, ...)`, found `{0}`", name);
+        }
+        attrs.extend(input.parenthesized()?.parse_terminated(Meta::parse, Token![,])?);
+        Ok(true)
+    }
+
+    /// Returns the attributes for the projected types returned by `project`,
+    /// `project_ref`, and `project_replace` methods.
+    pub(super) fn split(&self) -> (TokenStream, TokenStream, TokenStream) {
+        let Self { project, project_ref, project_replace } = self;
+        (quote!(#(#[#project])*), quote!(#(#[#project_ref])*), quote!(#(#[#project_replace])*))
     }
 }
 
@@ -221,6 +758,38 @@ pub(super) enum UnpinImpl {
     Negative(Span),
 }
 
+/// `<method>_fn = <ident>` or `!<method>` argument.
+pub(super) enum ProjFn {
+    Default,
+    /// `<method>_fn = <ident>`.
+    Named(Ident),
+    /// `!<method>`.
+    Suppressed(Span),
+}
+
+impl ProjFn {
+    fn new(ident: Option<Ident>, suppressed: Option<Span>, method: &str) -> Result<Self> {
+        match (ident, suppressed) {
+            (None, None) => Ok(Self::Default),
+            (Some(ident), None) => Ok(Self::Named(ident)),
+            (None, Some(span)) => Ok(Self::Suppressed(span)),
+            (Some(_), Some(span)) => Err(Error::new(
+                span,
+                format!("arguments `!{method}` and `{method}_fn` are mutually exclusive"),
+            )),
+        }
+    }
+
+    /// Returns the name of the method, or `None` if the method is suppressed.
+    pub(super) fn ident(&self, default: &str) -> Option<Ident> {
+        match self {
+            Self::Default => Some(Ident::new(default, Span::call_site())),
+            Self::Named(ident) => Some(ident.clone()),
+            Self::Suppressed(_) => None,
+        }
+    }
+}
+
 /// `project_replace [= <ident>]` argument.
 pub(super) enum ProjReplace {
     None,
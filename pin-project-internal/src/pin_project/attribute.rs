@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{ToTokens as _, quote};
 use syn::{
     Attribute, Result, Token, Visibility,
     parse::{Parse, ParseStream},
 };
 
-use super::PIN;
+use super::{PIN, args::find_crate_path};
 use crate::utils::SliceExt as _;
 
 // To generate the correct `Unpin` implementation and the projection methods,
@@ -26,10 +26,14 @@ use crate::utils::SliceExt as _;
 
 pub(super) fn parse_attribute(args: &TokenStream, input: TokenStream) -> Result<TokenStream> {
     let Input { attrs, body } = syn::parse2(input)?;
+    let crate_path = match find_crate_path(args) {
+        Some(path) => path.into_token_stream(),
+        None => quote!(::pin_project),
+    };
 
     Ok(quote! {
         #(#attrs)*
-        #[derive(::pin_project::__private::__PinProjectInternalDerive)]
+        #[derive(#crate_path::__private::__PinProjectInternalDerive)]
         // Use `__private` to prevent users from trying to control `InternalDerive`
         // manually. `__private` does not guarantee compatibility between patch
         // versions, so it should be sufficient for this purpose in most cases.
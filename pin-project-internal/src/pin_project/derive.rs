@@ -1,20 +1,21 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Delimiter, Group, Span, TokenStream};
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
 use quote::{ToTokens as _, format_ident, quote, quote_spanned};
 use syn::{
     Attribute, Error, Field, Fields, FieldsNamed, FieldsUnnamed, Generics, Ident, Index, Item,
-    Lifetime, LifetimeParam, Meta, Result, Token, Type, Variant, Visibility, WhereClause,
-    parse_quote, punctuated::Punctuated, token, visit_mut::VisitMut as _,
+    Lifetime, LifetimeParam, Meta, Path, Result, Token, Type, TypePath, Variant, Visibility,
+    WhereClause, parse::Parser as _, parse_quote, punctuated::Punctuated, token,
+    visit_mut::VisitMut as _,
 };
 
-use super::{
-    PIN,
-    args::{Args, ProjReplace, UnpinImpl, parse_args},
+use super::args::{
+    Args, FieldArgs, ProjAttrs, ProjFn, ProjReplace, UnpinImpl, VariantArgs, parse_args,
+    parse_field_args, parse_variant_args,
 };
 use crate::utils::{
-    ReplaceReceiver, SliceExt as _, Variants, determine_lifetime_name, determine_visibility,
-    insert_lifetime_and_bound,
+    ReplaceReceiver, Variants, determine_lifetime_name, determine_visibility,
+    insert_lifetime_and_bound, to_snake_case,
 };
 
 pub(super) fn parse_derive(input: TokenStream) -> Result<TokenStream> {
@@ -70,6 +71,15 @@ impl GenerateTokens {
         let unpin_impl = make_unpin_impl(cx);
         let drop_impl = make_drop_impl(cx);
         let allowed_lints = global_allowed_lints();
+        let import_crate = match &cx.crate_path {
+            Some(path) => quote! {
+                use #path as _pin_project;
+            },
+            None => quote! {
+                #[allow(unused_extern_crates)]
+                extern crate pin_project as _pin_project;
+            },
+        };
 
         tokens.extend(quote! {
             // All items except projected types are generated inside a `const` scope.
@@ -97,8 +107,7 @@ impl GenerateTokens {
                 clippy::used_underscore_binding
             )]
             const _: () = {
-                #[allow(unused_extern_crates)]
-                extern crate pin_project as _pin_project;
+                #import_crate
                 #scoped
                 #unpin_impl
                 #drop_impl
@@ -121,9 +130,11 @@ fn global_allowed_lints() -> TokenStream {
         clippy::unknown_clippy_lints,
         clippy::absolute_paths,
         clippy::min_ident_chars,
+        clippy::partial_pub_fields, // This lint warns the private hidden field for skipped fields.
         clippy::pattern_type_mismatch,
         clippy::pub_with_shorthand,
         clippy::redundant_pub_crate, // This lint warns `pub(crate)` field in private struct.
+        clippy::same_name_method, // This lint warns projection methods of `PinProject` trait.
         clippy::single_char_lifetime_names,
         clippy::type_repetition_in_bounds, // https://github.com/rust-lang/rust-clippy/issues/4326
     }
@@ -132,11 +143,22 @@ fn global_allowed_lints() -> TokenStream {
 /// Returns attributes used on projected types.
 fn proj_allowed_lints(cx: &Context<'_>) -> (TokenStream, TokenStream, TokenStream) {
     let global_allowed_lints = global_allowed_lints();
+    let pub_allowed_lints = cx.pub_allowed_lints();
+    // These lints warn unnamed projected types used in the `PinProject` impl.
+    let pin_project_allowed_lints = cx.pin_project.map(|_| {
+        quote! {
+            missing_debug_implementations,
+            unnameable_types,
+            clippy::pub_underscore_fields,
+        }
+    });
     let proj_mut_allowed_lints = if cx.project { Some(&global_allowed_lints) } else { None };
     let proj_mut = quote! {
         #[allow(
             dead_code, // This lint warns unused fields/variants.
+            #pin_project_allowed_lints
             #proj_mut_allowed_lints
+            #pub_allowed_lints
             clippy::missing_docs_in_private_items,
             clippy::mut_mut // This lint warns `&mut &mut <ty>`.
         )]
@@ -145,7 +167,9 @@ fn proj_allowed_lints(cx: &Context<'_>) -> (TokenStream, TokenStream, TokenStrea
     let proj_ref = quote! {
         #[allow(
             dead_code, // This lint warns unused fields/variants.
+            #pin_project_allowed_lints
             #proj_ref_allowed_lints
+            #pub_allowed_lints
             clippy::missing_docs_in_private_items,
             clippy::ref_option_ref // This lint warns `&Option<&<ty>>`.
         )]
@@ -161,6 +185,7 @@ fn proj_allowed_lints(cx: &Context<'_>) -> (TokenStream, TokenStream, TokenStrea
         #[allow(
             dead_code, // This lint warns unused fields/variants.
             #proj_own_allowed_lints
+            #pub_allowed_lints
             #variant_size_differences
             clippy::missing_docs_in_private_items
         )]
@@ -173,21 +198,59 @@ struct Context<'a> {
     orig: OriginalType<'a>,
     /// The projected types.
     proj: ProjectedType,
-    /// Types of the pinned fields.
+    /// Types of the pinned fields, except for the fields with
+    /// `#[pin(unsafe_exclude_from_unpin)]` attribute.
     pinned_fields: Vec<&'a Type>,
+    /// Spans of `unsafe_exclude_from_unpin` arguments.
+    excluded_from_unpin: Vec<Span>,
+    /// Per-variant drop hooks specified by `#[pin(drop = <path>)]` attributes
+    /// on variants: the name of the per-variant projection method and the hook.
+    variant_drops: Vec<(Ident, Path)>,
     /// Kind of the original type: struct or enum
     kind: TypeKind,
 
-    /// `PinnedDrop` argument.
+    /// `PinnedDrop [= <path>]` argument.
     pinned_drop: Option<Span>,
+    /// `<path>` in `PinnedDrop = <path>` argument.
+    pinned_drop_fn: Option<Path>,
     /// `UnsafeUnpin` or `!Unpin` argument.
     unpin_impl: UnpinImpl,
+    /// `Unpin where <predicates>` argument.
+    unpin_bounds: Option<WhereClause>,
+    /// `negative_impl` argument.
+    negative_impl: Option<Span>,
+    /// `PinProject` argument.
+    pin_project: Option<Span>,
     /// `project` argument.
     project: bool,
     /// `project_ref` argument.
     project_ref: bool,
     /// `project_replace [= <ident>]` argument.
     project_replace: ProjReplace,
+    /// `project_fn = <ident>` or `!project` argument.
+    project_fn: ProjFn,
+    /// `project_ref_fn = <ident>` or `!project_ref` argument.
+    project_ref_fn: ProjFn,
+    /// `project_replace_fn = <ident>` argument.
+    project_replace_fn: Option<Ident>,
+    /// `project_take` argument.
+    project_take: Option<Span>,
+    /// `project_replace_with` argument.
+    project_replace_with: Option<Span>,
+    /// `project_transition` argument.
+    project_transition: Option<Span>,
+    /// Name of the type returned by `into_parts` method, specified by
+    /// `into_parts = <ident>` argument.
+    into_parts: Option<Ident>,
+    /// `accessors` argument.
+    accessors: Option<Span>,
+    /// `project_variants` argument.
+    project_variants: Option<Span>,
+    /// `project_attr(...)`, `project_ref_attr(...)`, and
+    /// `project_replace_attr(...)` arguments.
+    proj_attrs: ProjAttrs,
+    /// `crate = <path>` argument.
+    crate_path: Option<Path>,
 }
 
 impl<'a> Context<'a> {
@@ -198,13 +261,49 @@ impl<'a> Context<'a> {
         generics: &'a mut Generics,
         kind: TypeKind,
     ) -> Result<Self> {
-        let Args { pinned_drop, unpin_impl, project, project_ref, project_replace } =
-            parse_args(attrs)?;
+        let Args {
+            pinned_drop,
+            pinned_drop_fn,
+            unpin_impl,
+            unpin_bounds,
+            negative_impl,
+            pin_project,
+            project,
+            project_ref,
+            project_replace,
+            project_fn,
+            project_ref_fn,
+            project_replace_fn,
+            project_take,
+            project_replace_with,
+            project_transition,
+            into_parts,
+            accessors,
+            project_variants,
+            project_vis,
+            proj_attrs,
+            crate_path,
+        } = parse_args(attrs)?;
+
+        // Replace `_` with the name inferred from the original type name.
+        let infer = |name: Ident, suffix: &str| {
+            if name == "_" { format_ident!("{}{}", ident, suffix) } else { name }
+        };
+        let project = project.map(|name| infer(name, "Proj"));
+        let project_ref = project_ref.map(|name| infer(name, "ProjRef"));
+        let into_parts = into_parts.map(|name| infer(name, "Parts"));
+        let project_replace = match project_replace {
+            ProjReplace::Named { span, ident: name } => {
+                ProjReplace::Named { span, ident: infer(name, "ProjOwn") }
+            }
+            project_replace => project_replace,
+        };
 
-        if let Some(name) = [project.as_ref(), project_ref.as_ref(), project_replace.ident()]
-            .iter()
-            .filter_map(Option::as_ref)
-            .find(|name| **name == ident)
+        if let Some(name) =
+            [project.as_ref(), project_ref.as_ref(), project_replace.ident(), into_parts.as_ref()]
+                .iter()
+                .filter_map(Option::as_ref)
+                .find(|name| **name == ident)
         {
             bail!(name, "name `{}` is the same as the original type name", name);
         }
@@ -225,6 +324,25 @@ impl<'a> Context<'a> {
         let mut where_clause = generics.make_where_clause().clone();
         where_clause.predicates.push(pred);
 
+        let proj_vis = project_vis.unwrap_or_else(|| determine_visibility(vis));
+        if let Some(span) = pin_project {
+            // The named projected types are used in the `PinProject` impl, so
+            // they must be as visible as the original type (E0446).
+            let visible = match (vis, &proj_vis) {
+                (_, Visibility::Public(_)) | (Visibility::Inherited, _) => true,
+                (vis, proj_vis) => {
+                    vis.to_token_stream().to_string() == proj_vis.to_token_stream().to_string()
+                }
+            };
+            if (project.is_some() || project_ref.is_some()) && !visible {
+                return Err(Error::new(
+                    span,
+                    "`PinProject` argument requires the named projected types to be as visible \
+                     as the original type; consider using `project_vis = <visibility>` argument",
+                ));
+            }
+        }
+
         let own_ident = project_replace
             .ident()
             .cloned()
@@ -233,25 +351,197 @@ impl<'a> Context<'a> {
         Ok(Self {
             kind,
             pinned_drop,
+            pinned_drop_fn,
             unpin_impl,
+            unpin_bounds,
+            negative_impl,
+            pin_project,
             project: project.is_some(),
             project_ref: project_ref.is_some(),
             project_replace,
+            project_fn,
+            project_ref_fn,
+            project_replace_fn,
+            project_take,
+            project_replace_with,
+            project_transition,
+            into_parts,
+            accessors,
+            project_variants,
+            proj_attrs,
+            crate_path,
             proj: ProjectedType {
-                vis: determine_visibility(vis),
+                vis: proj_vis,
                 mut_ident: project.unwrap_or_else(|| format_ident!("__{}Projection", ident)),
                 ref_ident: project_ref.unwrap_or_else(|| format_ident!("__{}ProjectionRef", ident)),
                 own_ident,
                 lifetime,
                 generics: proj_generics,
                 where_clause,
+                has_fields: false,
             },
             orig: OriginalType { attrs, vis, ident, generics },
             pinned_fields: vec![],
+            excluded_from_unpin: vec![],
+            variant_drops: vec![],
         })
     }
 }
 
+impl Context<'_> {
+    /// Returns the visibility of the projected type returned by `project` or
+    /// `project_ref` method.
+    ///
+    /// Unnamed projected types have the same visibility as the original type
+    /// if `PinProject` argument is specified, because types less visible than
+    /// the original type cannot be used in the `PinProject` impl (E0446). They
+    /// cannot be named by user code anyway, because they are generated inside
+    /// the `const` scope.
+    fn proj_vis(&self, named: bool) -> &Visibility {
+        if named || self.pin_project.is_none() { &self.proj.vis } else { self.orig.vis }
+    }
+
+    /// Returns the generics and `where` clause of the projected types
+    /// returned by `project` and `project_ref` methods.
+    ///
+    /// If the projected types have no fields that borrow from the original
+    /// type, the lifetime is not added to them because it would be unused.
+    fn proj_generics(&self) -> (&Generics, Option<&WhereClause>) {
+        if self.proj.has_fields {
+            (&self.proj.generics, Some(&self.proj.where_clause))
+        } else {
+            (self.orig.generics, self.orig.generics.where_clause.as_ref())
+        }
+    }
+
+    /// Returns the path to the `pin_project` crate, used in items generated
+    /// outside of the `const` scope.
+    fn crate_path(&self) -> TokenStream {
+        match &self.crate_path {
+            Some(path) => path.to_token_stream(),
+            None => quote!(::pin_project),
+        }
+    }
+
+    /// Returns lints to allow on the projected types and the projection
+    /// methods if they are `pub`, i.e., part of the public API of the crate.
+    fn pub_allowed_lints(&self) -> Option<TokenStream> {
+        if !matches!(self.proj.vis, Visibility::Public(_)) {
+            return None;
+        }
+        Some(match self.kind {
+            Struct => quote! { missing_docs, clippy::exhaustive_structs, },
+            Enum => quote! { missing_docs, clippy::exhaustive_enums, },
+        })
+    }
+
+    /// Returns a statement that binds the function specified by
+    /// `PinnedDrop = <path>` argument to `__pinned_drop`.
+    ///
+    /// This statement must be generated outside of any `unsafe` block, so that
+    /// `<path>` is checked in the user's safe context (e.g., reading a
+    /// `static mut` still requires `unsafe`). The function is coerced to a safe
+    /// function pointer, so an `unsafe fn` is not accepted either.
+    fn bind_pinned_drop_fn(&self, span: Span) -> Option<TokenStream> {
+        self.pinned_drop_fn.as_ref().map(|path| {
+            quote_spanned! { span =>
+                let __pinned_drop: fn(_pin_project::__private::Pin<&mut Self>) = #path;
+            }
+        })
+    }
+
+    /// Returns a call to the pinned drop routine of the original type:
+    /// `PinnedDrop::drop`, or the function bound by [`Self::bind_pinned_drop_fn`].
+    fn call_pinned_drop(&self, span: Span, pinned_self: &TokenStream) -> TokenStream {
+        if self.pinned_drop_fn.is_some() {
+            quote_spanned! { span => __pinned_drop(#pinned_self) }
+        } else {
+            quote_spanned! { span =>
+                _pin_project::__private::PinnedDrop::drop(#pinned_self)
+            }
+        }
+    }
+
+    /// Creates the calls to the drop hooks declared by `#[pin(drop = <path>)]`
+    /// attributes on variants. Only the hook for the active variant is called.
+    fn call_variant_drops(&self, pinned_self: &TokenStream) -> TokenStream {
+        let hooks = self.variant_drops.iter().map(|(project_fn, path)| {
+            quote! {
+                if let _pin_project::__private::Option::Some(__this) =
+                    __pinned_self.as_mut().#project_fn()
+                {
+                    #path(__this);
+                }
+            }
+        });
+        quote! {{
+            let mut __pinned_self: _pin_project::__private::Pin<&mut Self> = #pinned_self;
+            #(#hooks)*
+        }}
+    }
+
+    /// Returns the attribute to allow `dead_code` lint on the generated methods.
+    fn allow_dead_code(&self) -> TokenStream {
+        if self.pub_allowed_lints().is_some() {
+            quote! { #[allow(dead_code, missing_docs)] }
+        } else {
+            quote! { #[allow(dead_code)] }
+        }
+    }
+
+    /// Returns the names of the inherent methods generated by the arguments
+    /// of `#[pin_project]` attribute, except for the accessor methods and the
+    /// per-variant methods.
+    fn method_names(&self) -> Vec<Ident> {
+        let mut names = vec![];
+        if self.kind == Struct || self.project {
+            names.extend(self.project_fn.ident("project"));
+        }
+        if self.kind == Struct || self.project_ref {
+            names.extend(self.project_ref_fn.ident("project_ref"));
+        }
+        let has_project_replace = match self.kind {
+            Struct => self.project_replace.span().is_some(),
+            Enum => self.project_replace.ident().is_some(),
+        };
+        if has_project_replace {
+            names.push(
+                self.project_replace_fn.clone().unwrap_or_else(|| format_ident!("project_replace")),
+            );
+            if self.project_take.is_some() {
+                names.push(format_ident!("project_take"));
+            }
+            if self.project_replace_with.is_some() {
+                names.push(format_ident!("project_replace_with"));
+            }
+            if self.project_transition.is_some() {
+                names.push(format_ident!("project_transition"));
+            }
+        }
+        if self.into_parts.is_some() {
+            names.push(format_ident!("into_parts"));
+        }
+        names
+    }
+
+    /// Returns the names of the projected types named by the arguments of
+    /// `#[pin_project]` attribute, except for the per-variant projected types.
+    fn type_names(&self) -> Vec<Ident> {
+        let mut names = vec![];
+        if self.project {
+            names.push(self.proj.mut_ident.clone());
+        }
+        if self.project_ref {
+            names.push(self.proj.ref_ident.clone());
+        }
+        if self.project_replace.ident().is_some() {
+            names.push(self.proj.own_ident.clone());
+        }
+        names.extend(self.into_parts.clone());
+        names
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum TypeKind {
     Enum,
@@ -286,6 +576,9 @@ struct ProjectedType {
     /// `where` clause of the projected types. This has an additional
     /// bound generated by `insert_lifetime_and_bound`
     where_clause: WhereClause,
+    /// Whether the projected types have any fields that borrow from the
+    /// original type.
+    has_fields: bool,
 }
 
 struct ProjectedVariants {
@@ -295,29 +588,24 @@ struct ProjectedVariants {
     proj_arms: TokenStream,
     proj_ref_arms: TokenStream,
     proj_own_arms: TokenStream,
+    /// Per-variant projected types generated by `project_variants` argument.
+    variant_types: TokenStream,
+    /// Per-variant methods generated by `project_variants` argument.
+    variant_methods: TokenStream,
 }
 
 #[derive(Default)]
 struct ProjectedFields {
     proj_pat: TokenStream,
+    proj_own_pat: TokenStream,
     proj_body: TokenStream,
+    proj_ref_body: TokenStream,
     proj_own_body: TokenStream,
     proj_fields: TokenStream,
     proj_ref_fields: TokenStream,
     proj_own_fields: TokenStream,
 }
 
-fn validate_struct(ident: &Ident, fields: &Fields) -> Result<()> {
-    if fields.is_empty() {
-        let msg = "#[pin_project] attribute may not be used on structs with zero fields";
-        if let Fields::Unit = fields {
-            bail!(ident, msg)
-        }
-        bail!(fields, msg)
-    }
-    Ok(())
-}
-
 fn validate_enum(brace_token: token::Brace, variants: &Variants) -> Result<()> {
     if variants.is_empty() {
         return Err(Error::new(
@@ -325,22 +613,7 @@ fn validate_enum(brace_token: token::Brace, variants: &Variants) -> Result<()> {
             "#[pin_project] attribute may not be used on enums without variants",
         ));
     }
-    let has_field = variants.iter().try_fold(false, |has_field, v| {
-        if let Some((_, e)) = &v.discriminant {
-            bail!(e, "#[pin_project] attribute may not be used on enums with discriminants");
-        } else if let Some(attr) = v.attrs.find(PIN) {
-            bail!(attr, "#[pin] attribute may only be used on fields of structs or variants");
-        } else if v.fields.is_empty() {
-            Ok(has_field)
-        } else {
-            Ok(true)
-        }
-    })?;
-    if has_field {
-        Ok(())
-    } else {
-        bail!(variants, "#[pin_project] attribute may not be used on enums with zero fields");
-    }
+    Ok(())
 }
 
 fn parse_struct<'a>(
@@ -351,11 +624,11 @@ fn parse_struct<'a>(
     // Do this first for a better error message.
     let packed_check = ensure_not_packed(&cx.orig, Some(fields))?;
 
-    validate_struct(cx.orig.ident, fields)?;
-
     let ProjectedFields {
         proj_pat,
+        proj_own_pat,
         proj_body,
+        proj_ref_body,
         proj_fields,
         proj_ref_fields,
         proj_own_fields,
@@ -363,7 +636,10 @@ fn parse_struct<'a>(
     } = match fields {
         Fields::Named(_) => visit_fields(cx, None, fields, Delimiter::Brace)?,
         Fields::Unnamed(_) => visit_fields(cx, None, fields, Delimiter::Parenthesis)?,
-        Fields::Unit => unreachable!(),
+        Fields::Unit => ProjectedFields {
+            proj_own_body: proj_own_body(cx, None, None, &[]),
+            ..Default::default()
+        },
     };
 
     let proj_ident = &cx.proj.mut_ident;
@@ -372,8 +648,7 @@ fn parse_struct<'a>(
     let vis = &cx.proj.vis;
     let mut orig_generics = cx.orig.generics.clone();
     let orig_where_clause = orig_generics.where_clause.take();
-    let proj_generics = &cx.proj.generics;
-    let proj_where_clause = &cx.proj.where_clause;
+    let (proj_generics, proj_where_clause) = cx.proj_generics();
 
     // For tuple structs, we need to generate `(T1, T2) where Foo: Bar`
     // For non-tuple structs, we need to generate `where Foo: Bar { field1: T }`
@@ -383,26 +658,31 @@ fn parse_struct<'a>(
             quote!(#proj_where_clause #proj_ref_fields),
             quote!(#orig_where_clause #proj_own_fields),
         ),
-        Fields::Unnamed(_) => (
+        Fields::Unnamed(_) | Fields::Unit => (
             quote!(#proj_fields #proj_where_clause;),
             quote!(#proj_ref_fields #proj_where_clause;),
             quote!(#proj_own_fields #orig_where_clause;),
         ),
-        Fields::Unit => unreachable!(),
     };
 
     let (proj_attrs, proj_ref_attrs, proj_own_attrs) = proj_allowed_lints(cx);
+    let (proj_fwd_attrs, proj_ref_fwd_attrs, proj_own_fwd_attrs) = cx.proj_attrs.split();
+    let proj_vis = cx.proj_vis(cx.project);
+    let proj_ref_vis = cx.proj_vis(cx.project_ref);
     generate.extend(cx.project, quote! {
         #proj_attrs
-        #vis struct #proj_ident #proj_generics #where_clause_fields
+        #proj_fwd_attrs
+        #proj_vis struct #proj_ident #proj_generics #where_clause_fields
     });
     generate.extend(cx.project_ref, quote! {
         #proj_ref_attrs
-        #vis struct #proj_ref_ident #proj_generics #where_clause_ref_fields
+        #proj_ref_fwd_attrs
+        #proj_ref_vis struct #proj_ref_ident #proj_generics #where_clause_ref_fields
     });
     if cx.project_replace.span().is_some() {
         generate.extend(cx.project_replace.ident().is_some(), quote! {
             #proj_own_attrs
+            #proj_own_fwd_attrs
             #vis struct #proj_own_ident #orig_generics #where_clause_own_fields
         });
     }
@@ -413,13 +693,43 @@ fn parse_struct<'a>(
     };
     let proj_ref_body = quote! {
         let Self #proj_pat = self.get_ref();
-        #proj_ref_ident #proj_body
+        #proj_ref_ident #proj_ref_body
     };
-    let proj_own_body = quote! {
+    let proj_ptr_body = quote! {
         let Self #proj_pat = &mut *__self_ptr;
+        #proj_ident #proj_body
+    };
+    let proj_own_body = quote! {
+        let Self #proj_own_pat = &mut *__self_ptr;
         #proj_own_body
     };
-    generate.extend(false, make_proj_impl(cx, &proj_mut_body, &proj_ref_body, &proj_own_body));
+    generate.extend(
+        false,
+        make_proj_impl(cx, &proj_mut_body, &proj_ref_body, &proj_ptr_body, &proj_own_body),
+    );
+    generate.extend(false, make_proj_trait_impl(cx, &proj_mut_body, &proj_ref_body));
+
+    if let Some(span) = cx.project_variants {
+        return Err(Error::new(span, "`project_variants` argument may only be used on enums"));
+    }
+
+    if let Some(parts_ident) = &cx.into_parts {
+        let (parts_type, parts_impl) = make_into_parts(cx, parts_ident, &[(None, fields)]);
+        generate.extend(true, parts_type);
+        generate.extend(false, parts_impl);
+    }
+
+    if let Some(span) = cx.accessors {
+        match fields {
+            Fields::Named(fields) => generate.extend(false, make_accessors_impl(cx, fields)?),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "`accessors` argument may only be used on structs with named fields",
+                ));
+            }
+        }
+    }
 
     generate.extend(false, packed_check);
     Ok(())
@@ -437,6 +747,29 @@ fn parse_enum<'a>(
             "`project_replace` argument requires a value when used on enums",
         ));
     }
+    if let Some(span) = cx.accessors {
+        return Err(Error::new(
+            span,
+            "`accessors` argument may only be used on structs with named fields",
+        ));
+    }
+    if let (ProjFn::Named(ident), false) = (&cx.project_fn, cx.project) {
+        bail!(ident, "`project_fn` argument requires `project` argument when used on enums");
+    }
+    if let (ProjFn::Named(ident), false) = (&cx.project_ref_fn, cx.project_ref) {
+        bail!(
+            ident,
+            "`project_ref_fn` argument requires `project_ref` argument when used on enums"
+        );
+    }
+    // The closure passed to `project_replace_with` method takes the projected
+    // type returned by `project` method, which must be named to be matched on.
+    if let (Some(span), false) = (cx.project_replace_with, cx.project) {
+        return Err(Error::new(
+            span,
+            "`project_replace_with` argument requires `project` argument when used on enums",
+        ));
+    }
 
     // #[repr(packed)] cannot be apply on enums and will be rejected by rustc.
     // However, we should not rely on the behavior of rustc that rejects this.
@@ -454,6 +787,8 @@ fn parse_enum<'a>(
         proj_arms,
         proj_ref_arms,
         proj_own_arms,
+        variant_types,
+        variant_methods,
     } = visit_variants(cx, variants)?;
 
     let proj_ident = &cx.proj.mut_ident;
@@ -462,22 +797,28 @@ fn parse_enum<'a>(
     let vis = &cx.proj.vis;
     let mut orig_generics = cx.orig.generics.clone();
     let orig_where_clause = orig_generics.where_clause.take();
-    let proj_generics = &cx.proj.generics;
-    let proj_where_clause = &cx.proj.where_clause;
+    let (proj_generics, proj_where_clause) = cx.proj_generics();
 
     let (proj_attrs, proj_ref_attrs, proj_own_attrs) = proj_allowed_lints(cx);
-    if cx.project {
-        generate.extend(true, quote! {
+    let (proj_fwd_attrs, proj_ref_fwd_attrs, proj_own_fwd_attrs) = cx.proj_attrs.split();
+    let proj_vis = cx.proj_vis(cx.project);
+    let proj_ref_vis = cx.proj_vis(cx.project_ref);
+    // Unnamed projected types are only generated if they are used in the
+    // `PinProject` impl, and they are not exposed.
+    if cx.project || cx.pin_project.is_some() {
+        generate.extend(cx.project, quote! {
             #proj_attrs
-            #vis enum #proj_ident #proj_generics #proj_where_clause {
+            #proj_fwd_attrs
+            #proj_vis enum #proj_ident #proj_generics #proj_where_clause {
                 #proj_variants
             }
         });
     }
-    if cx.project_ref {
-        generate.extend(true, quote! {
+    if cx.project_ref || cx.pin_project.is_some() {
+        generate.extend(cx.project_ref, quote! {
             #proj_ref_attrs
-            #vis enum #proj_ref_ident #proj_generics #proj_where_clause {
+            #proj_ref_fwd_attrs
+            #proj_ref_vis enum #proj_ref_ident #proj_generics #proj_where_clause {
                 #proj_ref_variants
             }
         });
@@ -485,6 +826,7 @@ fn parse_enum<'a>(
     if cx.project_replace.ident().is_some() {
         generate.extend(true, quote! {
             #proj_own_attrs
+            #proj_own_fwd_attrs
             #vis enum #proj_own_ident #orig_generics #orig_where_clause {
                 #proj_own_variants
             }
@@ -501,12 +843,39 @@ fn parse_enum<'a>(
             #proj_ref_arms
         }
     };
+    let proj_ptr_body = quote! {
+        match &mut *__self_ptr {
+            #proj_arms
+        }
+    };
     let proj_own_body = quote! {
         match &mut *__self_ptr {
             #proj_own_arms
         }
     };
-    generate.extend(false, make_proj_impl(cx, &proj_mut_body, &proj_ref_body, &proj_own_body));
+    generate.extend(
+        false,
+        make_proj_impl(cx, &proj_mut_body, &proj_ref_body, &proj_ptr_body, &proj_own_body),
+    );
+    generate.extend(false, make_proj_trait_impl(cx, &proj_mut_body, &proj_ref_body));
+
+    if let Some(parts_ident) = &cx.into_parts {
+        let variants: Vec<_> = variants.iter().map(|v| (Some(&v.ident), &v.fields)).collect();
+        let (parts_type, parts_impl) = make_into_parts(cx, parts_ident, &variants);
+        generate.extend(true, parts_type);
+        generate.extend(false, parts_impl);
+    }
+
+    if cx.project_variants.is_some() {
+        let orig_ident = cx.orig.ident;
+        let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
+        generate.extend(true, variant_types);
+        generate.extend(false, quote! {
+            impl #impl_generics #orig_ident #ty_generics #where_clause {
+                #variant_methods
+            }
+        });
+    }
 
     Ok(())
 }
@@ -518,16 +887,39 @@ fn visit_variants<'a>(cx: &mut Context<'a>, variants: &'a Variants) -> Result<Pr
     let mut proj_arms = TokenStream::new();
     let mut proj_ref_arms = TokenStream::new();
     let mut proj_own_arms = TokenStream::new();
-
-    for Variant { ident, fields, .. } in variants {
-        let ProjectedFields {
-            proj_pat,
-            proj_body,
-            proj_fields,
-            proj_ref_fields,
-            proj_own_fields,
-            proj_own_body,
-        } = match fields {
+    let mut variant_types = TokenStream::new();
+    let mut variant_methods = TokenStream::new();
+
+    // The names of the per-variant methods and types generated so far, and
+    // the variants for which they are generated (`None` for the other
+    // generated items).
+    let mut method_names: Vec<(Ident, Option<&Ident>)> =
+        cx.method_names().into_iter().map(|name| (name, None)).collect();
+    let mut type_names: Vec<(Ident, Option<&Ident>)> =
+        cx.type_names().into_iter().map(|name| (name, None)).collect();
+
+    // Discriminants are allowed. They are not included in the projected
+    // types, so the layout and discriminant values of the original type
+    // are unaffected.
+    for Variant { attrs, ident, fields, .. } in variants {
+        let VariantArgs { drop, proj_attrs } = parse_variant_args(attrs)?;
+        if let Some(path) = drop {
+            if let Some(span) = cx.pinned_drop {
+                return Err(Error::new(
+                    span,
+                    "`PinnedDrop` argument and `#[pin(drop = <path>)]` attribute are mutually exclusive",
+                ));
+            }
+            if cx.project_variants.is_none() {
+                bail!(
+                    path,
+                    "`#[pin(drop = <path>)]` attribute requires `project_variants` argument"
+                );
+            }
+            cx.variant_drops.push((format_ident!("project_{}", to_snake_case(ident)), path));
+        }
+        let (proj_attrs, proj_ref_attrs, proj_own_attrs) = proj_attrs.split();
+        let projected = match fields {
             Fields::Named(_) => visit_fields(cx, Some(ident), fields, Delimiter::Brace)?,
             Fields::Unnamed(_) => visit_fields(cx, Some(ident), fields, Delimiter::Parenthesis)?,
             Fields::Unit => ProjectedFields {
@@ -535,26 +927,51 @@ fn visit_variants<'a>(cx: &mut Context<'a>, variants: &'a Variants) -> Result<Pr
                 ..Default::default()
             },
         };
+        if cx.project_variants.is_some() {
+            let (types, methods) = make_variant_proj(
+                cx,
+                ident,
+                fields,
+                &projected,
+                &mut method_names,
+                &mut type_names,
+            )?;
+            variant_types.extend(types);
+            variant_methods.extend(methods);
+        }
+        let ProjectedFields {
+            proj_pat,
+            proj_own_pat,
+            proj_body,
+            proj_ref_body,
+            proj_fields,
+            proj_ref_fields,
+            proj_own_fields,
+            proj_own_body,
+        } = projected;
 
         let proj_ident = &cx.proj.mut_ident;
         let proj_ref_ident = &cx.proj.ref_ident;
         proj_variants.extend(quote! {
+            #proj_attrs
             #ident #proj_fields,
         });
         proj_ref_variants.extend(quote! {
+            #proj_ref_attrs
             #ident #proj_ref_fields,
         });
         proj_own_variants.extend(quote! {
+            #proj_own_attrs
             #ident #proj_own_fields,
         });
         proj_arms.extend(quote! {
             Self::#ident #proj_pat => #proj_ident::#ident #proj_body,
         });
         proj_ref_arms.extend(quote! {
-            Self::#ident #proj_pat => #proj_ref_ident::#ident #proj_body,
+            Self::#ident #proj_pat => #proj_ref_ident::#ident #proj_ref_body,
         });
         proj_own_arms.extend(quote! {
-            Self::#ident #proj_pat => { #proj_own_body }
+            Self::#ident #proj_own_pat => { #proj_own_body }
         });
     }
 
@@ -565,9 +982,192 @@ fn visit_variants<'a>(cx: &mut Context<'a>, variants: &'a Variants) -> Result<Pr
         proj_arms,
         proj_ref_arms,
         proj_own_arms,
+        variant_types,
+        variant_methods,
     })
 }
 
+/// Creates the per-variant projected structs and the `is_<variant>`,
+/// `project_<variant>`, and `project_<variant>_ref` methods for a variant.
+fn make_variant_proj<'a>(
+    cx: &Context<'_>,
+    variant_ident: &'a Ident,
+    fields: &Fields,
+    projected: &ProjectedFields,
+    method_names: &mut Vec<(Ident, Option<&'a Ident>)>,
+    type_names: &mut Vec<(Ident, Option<&'a Ident>)>,
+) -> Result<(TokenStream, TokenStream)> {
+    let vis = &cx.proj.vis;
+    let lifetime = &cx.proj.lifetime;
+    let orig_ident = cx.orig.ident;
+    let orig_ty_generics = cx.orig.generics.split_for_impl().1;
+    let proj_generics = &cx.proj.generics;
+    let proj_where_clause = &cx.proj.where_clause;
+    let proj_ty_generics = proj_generics.split_for_impl().1;
+    let crate_path = cx.crate_path();
+    let global_allowed_lints = global_allowed_lints();
+    let pub_allowed_lints = cx.pub_allowed_lints();
+    let allow_dead_code = cx.allow_dead_code();
+    let snake_case = to_snake_case(variant_ident);
+    let is_ident = format_ident!("is_{}", snake_case);
+    let proj_ident = format_ident!("{}{}Proj", orig_ident, variant_ident);
+    let proj_ref_ident = format_ident!("{}{}ProjRef", orig_ident, variant_ident);
+    let project_ident = format_ident!("project_{}", snake_case);
+    let project_ref_ident = format_ident!("project_{}_ref", snake_case);
+    check_variant_names(
+        method_names,
+        [&is_ident, &project_ident, &project_ref_ident],
+        variant_ident,
+        "method",
+    )?;
+    check_variant_names(type_names, [&proj_ident, &proj_ref_ident], variant_ident, "type")?;
+
+    let mut types = TokenStream::new();
+    let mut methods = quote! {
+        #allow_dead_code
+        #[inline]
+        #vis fn #is_ident(&self) -> bool {
+            #[allow(unreachable_patterns)]
+            match self {
+                Self::#variant_ident { .. } => true,
+                _ => false,
+            }
+        }
+    };
+    let proj_pat = &projected.proj_pat;
+    for (proj_ident, method_ident, proj_fields, proj_body, receiver, get_mut) in [
+        (
+            proj_ident,
+            project_ident,
+            &projected.proj_fields,
+            &projected.proj_body,
+            quote!(&#lifetime mut),
+            quote!(get_unchecked_mut),
+        ),
+        (
+            proj_ref_ident,
+            project_ref_ident,
+            &projected.proj_ref_fields,
+            &projected.proj_ref_body,
+            quote!(&#lifetime),
+            quote!(get_ref),
+        ),
+    ] {
+        // The fields of variants have no visibility, so they are given the
+        // visibility of the projected types. The phantom field uses the lifetime
+        // and all the generic parameters, which may be unused by the fields.
+        let phantom_ty = quote! {
+            #crate_path::__private::PhantomData<#receiver #orig_ident #orig_ty_generics>
+        };
+        let phantom = quote!(_pin_project::__private::PhantomData);
+        let (proj_fields, proj_body) = match fields {
+            Fields::Named(_) => {
+                let mut proj_fields: FieldsNamed = syn::parse2(proj_fields.clone())?;
+                proj_fields
+                    .named
+                    .push(Field::parse_named.parse2(quote!(__pin_project_phantom: #phantom_ty))?);
+                proj_fields.named.iter_mut().for_each(|field| field.vis = vis.clone());
+                let phantom = quote!(__pin_project_phantom: #phantom,);
+                (quote!(#proj_where_clause #proj_fields), append_to_group(proj_body, phantom))
+            }
+            Fields::Unnamed(_) | Fields::Unit => {
+                let mut proj_fields: FieldsUnnamed = if proj_fields.is_empty() {
+                    parse_quote!(())
+                } else {
+                    syn::parse2(proj_fields.clone())?
+                };
+                proj_fields.unnamed.push(Field::parse_unnamed.parse2(phantom_ty)?);
+                proj_fields.unnamed.iter_mut().for_each(|field| field.vis = vis.clone());
+                (
+                    quote!(#proj_fields #proj_where_clause;),
+                    append_to_group(proj_body, quote!(#phantom,)),
+                )
+            }
+        };
+
+        types.extend(quote! {
+            #[allow(
+                dead_code, // This lint warns unused fields.
+                #global_allowed_lints
+                #pub_allowed_lints
+                // This lint warns fields with the visibility of the projected types.
+                clippy::field_scoped_visibility_modifiers,
+                clippy::missing_docs_in_private_items,
+                clippy::mut_mut, // This lint warns `&mut &mut <ty>`.
+                clippy::pub_underscore_fields,
+                clippy::ref_option_ref // This lint warns `&Option<&<ty>>`.
+            )]
+            #vis struct #proj_ident #proj_generics #proj_fields
+        });
+        methods.extend(quote! {
+            #allow_dead_code
+            #[allow(unreachable_patterns)]
+            #[inline]
+            #vis fn #method_ident<#lifetime>(
+                self: _pin_project::__private::Pin<#receiver Self>,
+            ) -> _pin_project::__private::Option<#proj_ident #proj_ty_generics> {
+                unsafe {
+                    match self.#get_mut() {
+                        Self::#variant_ident #proj_pat => {
+                            _pin_project::__private::Option::Some(#proj_ident #proj_body)
+                        }
+                        _ => _pin_project::__private::Option::None,
+                    }
+                }
+            }
+        });
+    }
+
+    Ok((types, methods))
+}
+
+/// Checks that the names of the per-variant methods or types generated for
+/// `variant_ident` do not conflict with the names generated so far, and records
+/// them.
+fn check_variant_names<'a, const N: usize>(
+    names: &mut Vec<(Ident, Option<&'a Ident>)>,
+    new_names: [&Ident; N],
+    variant_ident: &'a Ident,
+    kind: &str,
+) -> Result<()> {
+    for name in new_names {
+        match names.iter().find(|(other, _)| other == name) {
+            Some((_, Some(other))) => bail!(
+                variant_ident,
+                "{} `{}` for variant `{}` conflicts with the {} for variant `{}`; consider renaming one of the variants",
+                kind,
+                name,
+                variant_ident,
+                kind,
+                other,
+            ),
+            Some((_, None)) => bail!(
+                variant_ident,
+                "{} `{}` for variant `{}` conflicts with another {} generated by #[pin_project] attribute; consider renaming the variant",
+                kind,
+                name,
+                variant_ident,
+                kind,
+            ),
+            None => names.push((name.clone(), Some(variant_ident))),
+        }
+    }
+    Ok(())
+}
+
+/// Appends `tokens` to the contents of the group in `group`, or creates a
+/// parenthesized group of `tokens` if `group` is empty.
+fn append_to_group(group: &TokenStream, tokens: TokenStream) -> TokenStream {
+    match group.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) => {
+            let mut stream = group.stream();
+            stream.extend(tokens);
+            Group::new(group.delimiter(), stream).into_token_stream()
+        }
+        _ => Group::new(Delimiter::Parenthesis, tokens).into_token_stream(),
+    }
+}
+
 fn visit_fields<'a>(
     cx: &mut Context<'a>,
     variant_ident: Option<&Ident>,
@@ -579,67 +1179,203 @@ fn visit_fields<'a>(
     }
 
     let mut proj_pat = TokenStream::new();
+    let mut proj_own_pat = TokenStream::new();
     let mut proj_body = TokenStream::new();
+    let mut proj_ref_body = TokenStream::new();
     let mut proj_fields = TokenStream::new();
     let mut proj_ref_fields = TokenStream::new();
     let mut proj_own_fields = TokenStream::new();
     let mut proj_move = TokenStream::new();
-    let mut pinned_bindings = Vec::with_capacity(fields.len());
+    let mut drop_bindings = Vec::with_capacity(fields.len());
+    let mut skipped_tys = vec![];
+    let crate_path = cx.crate_path();
 
     for (i, Field { attrs, vis, ident, colon_token, ty, .. }) in fields.iter().enumerate() {
         let binding = ident.clone().unwrap_or_else(|| format_ident!("_{}", i));
-        proj_pat.extend(quote!(#binding,));
+        proj_own_pat.extend(quote!(#binding,));
         let lifetime = &cx.proj.lifetime;
-        if attrs.position_exact(PIN)?.is_some() {
-            proj_fields.extend(quote! {
-                #vis #ident #colon_token ::pin_project::__private::Pin<&#lifetime mut (#ty)>,
-            });
-            proj_ref_fields.extend(quote! {
-                #vis #ident #colon_token ::pin_project::__private::Pin<&#lifetime (#ty)>,
-            });
+        let FieldArgs { pinned, project, elements, skip, unsafe_exclude_from_unpin, proj_attrs } =
+            parse_field_args(attrs)?;
+        if let Some(span) = unsafe_exclude_from_unpin {
+            let arg = match cx.unpin_impl {
+                UnpinImpl::Default => None,
+                UnpinImpl::Unsafe(_) => Some("UnsafeUnpin"),
+                UnpinImpl::Negative(_) => Some("!Unpin"),
+            };
+            if let Some(arg) = arg {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "arguments `unsafe_exclude_from_unpin` and `{arg}` are mutually exclusive"
+                    ),
+                ));
+            }
+            cx.excluded_from_unpin.push(span);
+        }
+        if skip.is_some() {
+            // Skipped fields are not projected, but are dropped in place by
+            // `project_replace`, regardless of whether they are pinned.
+            proj_pat.extend(quote!(#ident #colon_token _,));
+            if pinned && unsafe_exclude_from_unpin.is_none() {
+                cx.pinned_fields.push(ty);
+            }
+            drop_bindings.push(binding);
+            if ident.is_none() {
+                // Fields of tuple structs and tuple variants are replaced with a
+                // hidden field at the same position, so that the indices of
+                // the following fields are preserved.
+                let phantom_field = quote!(#crate_path::__private::PhantomData<fn() -> #ty>,);
+                proj_fields.extend(phantom_field.clone());
+                proj_ref_fields.extend(phantom_field.clone());
+                proj_own_fields.extend(phantom_field);
+                let phantom = quote!(_pin_project::__private::PhantomData,);
+                proj_body.extend(phantom.clone());
+                proj_ref_body.extend(phantom.clone());
+                proj_move.extend(phantom);
+            } else {
+                skipped_tys.push(ty);
+            }
+            continue;
+        }
+        proj_pat.extend(quote!(#binding,));
+        cx.proj.has_fields = true;
+        let (proj_attrs, proj_ref_attrs, proj_own_attrs) = proj_attrs.split();
+        if pinned {
+            if let Some(span) = project {
+                // `#[pin(project)]` field is projected to the projected type of the field type.
+                // The projected types have the span of the argument, so that the error
+                // is reported at the argument if the field type does not implement
+                // `PinProject`.
+                let proj_ty = quote_spanned! { span =>
+                    <#ty as #crate_path::PinProject>::Proj<#lifetime>
+                };
+                let proj_ref_ty = quote_spanned! { span =>
+                    <#ty as #crate_path::PinProject>::ProjRef<#lifetime>
+                };
+                proj_fields.extend(quote! {
+                    #proj_attrs
+                    #vis #ident #colon_token #proj_ty,
+                });
+                proj_ref_fields.extend(quote! {
+                    #proj_ref_attrs
+                    #vis #ident #colon_token #proj_ref_ty,
+                });
+                proj_body.extend(quote_spanned! { span =>
+                    #ident #colon_token _pin_project::PinProject::project(
+                        _pin_project::__private::Pin::new_unchecked(#binding),
+                    ),
+                });
+                proj_ref_body.extend(quote_spanned! { span =>
+                    #ident #colon_token _pin_project::PinProject::project_ref(
+                        _pin_project::__private::Pin::new_unchecked(#binding),
+                    ),
+                });
+            } else if elements.is_some() {
+                // `#[pin(elements)]` field is projected to the pinned view of
+                // the elements of the array or slice.
+                let elem = element_type(ty)?;
+                proj_fields.extend(quote! {
+                    #proj_attrs
+                    #vis #ident #colon_token #crate_path::slice::PinSliceMut<#lifetime, #elem>,
+                });
+                proj_ref_fields.extend(quote! {
+                    #proj_ref_attrs
+                    #vis #ident #colon_token #crate_path::slice::PinSliceRef<#lifetime, #elem>,
+                });
+                proj_body.extend(quote! {
+                    #ident #colon_token _pin_project::slice::PinSliceMut::new(
+                        _pin_project::__private::Pin::new_unchecked(#binding),
+                    ),
+                });
+                proj_ref_body.extend(quote! {
+                    #ident #colon_token _pin_project::slice::PinSliceRef::new(
+                        _pin_project::__private::Pin::new_unchecked(#binding),
+                    ),
+                });
+            } else {
+                proj_fields.extend(quote! {
+                    #proj_attrs
+                    #vis #ident #colon_token #crate_path::__private::Pin<&#lifetime mut (#ty)>,
+                });
+                proj_ref_fields.extend(quote! {
+                    #proj_ref_attrs
+                    #vis #ident #colon_token #crate_path::__private::Pin<&#lifetime (#ty)>,
+                });
+                let body = quote! {
+                    #ident #colon_token _pin_project::__private::Pin::new_unchecked(#binding),
+                };
+                proj_body.extend(body.clone());
+                proj_ref_body.extend(body);
+            }
             proj_own_fields.extend(quote! {
-                #vis #ident #colon_token ::pin_project::__private::PhantomData<#ty>,
-            });
-            proj_body.extend(quote! {
-                #ident #colon_token _pin_project::__private::Pin::new_unchecked(#binding),
+                #proj_own_attrs
+                #vis #ident #colon_token #crate_path::__private::PhantomData<#ty>,
             });
             proj_move.extend(quote! {
                 #ident #colon_token _pin_project::__private::PhantomData,
             });
 
-            cx.pinned_fields.push(ty);
-            pinned_bindings.push(binding);
+            if unsafe_exclude_from_unpin.is_none() {
+                cx.pinned_fields.push(ty);
+            }
+            drop_bindings.push(binding);
         } else {
             proj_fields.extend(quote! {
+                #proj_attrs
                 #vis #ident #colon_token &#lifetime mut (#ty),
             });
             proj_ref_fields.extend(quote! {
+                #proj_ref_attrs
                 #vis #ident #colon_token &#lifetime (#ty),
             });
             proj_own_fields.extend(quote! {
+                #proj_own_attrs
                 #vis #ident #colon_token #ty,
             });
             proj_body.extend(quote! {
                 #binding,
             });
+            proj_ref_body.extend(quote! {
+                #binding,
+            });
             proj_move.extend(quote! {
                 #ident #colon_token _pin_project::__private::ptr::read(#binding),
             });
         }
     }
 
+    if !skipped_tys.is_empty() {
+        // Use the types of the skipped fields so that the generic parameters
+        // used only by them are not unused in the projected types. The hidden
+        // field is private, regardless of the visibility of the skipped fields.
+        let phantom_field = quote! {
+            __pin_project_skipped: #crate_path::__private::PhantomData<fn() -> (#(#skipped_tys,)*)>,
+        };
+        proj_fields.extend(phantom_field.clone());
+        proj_ref_fields.extend(phantom_field.clone());
+        proj_own_fields.extend(phantom_field);
+        let phantom = quote!(__pin_project_skipped: _pin_project::__private::PhantomData,);
+        proj_body.extend(phantom.clone());
+        proj_ref_body.extend(phantom.clone());
+        proj_move.extend(phantom);
+    }
+
     let proj_pat = surround(delim, proj_pat);
+    let proj_own_pat = surround(delim, proj_own_pat);
     let proj_body = surround(delim, proj_body);
+    let proj_ref_body = surround(delim, proj_ref_body);
     let proj_fields = surround(delim, proj_fields);
     let proj_ref_fields = surround(delim, proj_ref_fields);
     let proj_own_fields = surround(delim, proj_own_fields);
 
     let proj_move = Group::new(delim, proj_move);
-    let proj_own_body = proj_own_body(cx, variant_ident, Some(&proj_move), &pinned_bindings);
+    let proj_own_body = proj_own_body(cx, variant_ident, Some(&proj_move), &drop_bindings);
 
     Ok(ProjectedFields {
         proj_pat,
+        proj_own_pat,
         proj_body,
+        proj_ref_body,
         proj_own_body,
         proj_fields,
         proj_ref_fields,
@@ -649,12 +1385,15 @@ fn visit_fields<'a>(
 
 /// Generates the processing that `project_replace` does for the struct or each variant.
 ///
-/// Note: `pinned_fields` must be in declaration order.
+/// `drop_fields` are the fields that are dropped in place instead of being
+/// moved: the pinned fields and the skipped fields.
+///
+/// Note: `drop_fields` must be in declaration order.
 fn proj_own_body(
     cx: &Context<'_>,
     variant_ident: Option<&Ident>,
     proj_move: Option<&Group>,
-    pinned_fields: &[Ident],
+    drop_fields: &[Ident],
 ) -> TokenStream {
     let ident = &cx.proj.own_ident;
     let proj_own = match variant_ident {
@@ -665,20 +1404,20 @@ fn proj_own_body(
     // The fields of the struct and the active enum variant are dropped
     // in declaration order.
     // Refs: https://doc.rust-lang.org/reference/destructors.html
-    let pinned_fields = pinned_fields.iter().rev();
+    let drop_fields = drop_fields.iter().rev();
 
     quote! {
         // First, extract all the unpinned fields.
         let __result = #proj_own #proj_move;
 
-        // Now create guards to drop all the pinned fields.
+        // Now create guards to drop all the pinned (and skipped) fields.
         //
         // Due to a compiler bug (https://github.com/rust-lang/rust/issues/47949)
         // this must be in its own scope, or else `__result` will not be dropped
         // if any of the destructors panic.
         {
             #(
-                let __guard = _pin_project::__private::UnsafeDropInPlaceGuard::new(#pinned_fields);
+                let __guard = _pin_project::__private::UnsafeDropInPlaceGuard::new(#drop_fields);
             )*
         }
 
@@ -687,12 +1426,15 @@ fn proj_own_body(
     }
 }
 
+#[allow(clippy::doc_overindented_list_items)]
 /// Creates `Unpin` implementation for the original type.
 ///
 /// The kind of `Unpin` impl generated depends on `unpin_impl` field:
 /// - `UnpinImpl::Unsafe` - Implements `Unpin` via `UnsafeUnpin` impl.
-/// - `UnpinImpl::Negative` - Generates `Unpin` impl with bounds that will never be true.
-/// - `UnpinImpl::Default` - Generates `Unpin` impl that requires `Unpin` for all pinned fields.
+/// - `UnpinImpl::Negative` - Generates `Unpin` impl with bounds that will never be true, or
+///                            negative `Unpin` impl if `negative_impl` argument is specified.
+/// - `UnpinImpl::Default` - Generates `Unpin` impl that requires `Unpin` for all pinned fields,
+///                           and the predicates specified by `Unpin where` argument.
 fn make_unpin_impl(cx: &Context<'_>) -> TokenStream {
     match cx.unpin_impl {
         UnpinImpl::Unsafe(span) => {
@@ -717,6 +1459,27 @@ fn make_unpin_impl(cx: &Context<'_>) -> TokenStream {
                 }
             }
         }
+        UnpinImpl::Negative(span) if cx.negative_impl.is_some() => {
+            let orig_ident = cx.orig.ident;
+            let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
+
+            // For interoperability with `forbid(unsafe_code)`, `unsafe` token should be
+            // call-site span.
+            let unsafety = <Token![unsafe]>::default();
+            quote_spanned! { span =>
+                impl #impl_generics !_pin_project::__private::Unpin for #orig_ident #ty_generics
+                #where_clause
+                {
+                }
+
+                // Generate a dummy impl of `UnsafeUnpin`, to ensure that the user cannot implement it.
+                #[doc(hidden)]
+                #unsafety impl #impl_generics _pin_project::UnsafeUnpin for #orig_ident #ty_generics
+                #where_clause
+                {
+                }
+            }
+        }
         UnpinImpl::Negative(span) => {
             let mut proj_generics = cx.proj.generics.clone();
             let orig_ident = cx.orig.ident;
@@ -758,79 +1521,135 @@ fn make_unpin_impl(cx: &Context<'_>) -> TokenStream {
         }
         UnpinImpl::Default => {
             let mut impl_where_clause = cx.orig.generics.where_clause.clone().unwrap();
+            let orig_ident = cx.orig.ident;
+            let (_, ty_generics, ty_where_clause) = cx.orig.generics.split_for_impl();
 
-            // Generate a field in our new struct for every
-            // pinned field in the original type.
-            let fields = cx.pinned_fields.iter().enumerate().map(|(i, ty)| {
-                let field_ident = format_ident!("__field{}", i);
-                quote!(#field_ident: #ty)
-            });
-
-            // We could try to determine the subset of type parameters
-            // and lifetimes that are actually used by the pinned fields
-            // (as opposed to those only used by unpinned fields).
-            // However, this would be tricky and error-prone, since
-            // it's possible for users to create types that would alias
-            // with generic parameters (e.g. 'struct T').
-            //
-            // Instead, we generate a use of every single type parameter
-            // and lifetime used in the original struct. For type parameters,
-            // we generate code like this:
-            //
-            // ```
-            // struct AlwaysUnpin<T: ?Sized>(PhantomData<T>) {}
-            // impl<T: ?Sized> Unpin for AlwaysUnpin<T> {}
+            // If all pinned fields are type parameters, add bounds for them
+            // directly, so that rustdoc shows readable bounds like
+            // `PinnedFieldsOf<T>: Unpin`. Otherwise, add bounds via the struct
+            // below.
             //
-            // ...
-            // _field: AlwaysUnpin<(A, B, C)>
-            // ```
+            // The type parameters are still wrapped in `PinnedFieldsOf`, because
+            // a bare `T: Unpin` bound allows the user to write an overlapping
+            // `Unpin` impl for a type that is never `Unpin` (e.g., a type with
+            // `impl Unpin for MyPhantomPinned where for<'a> str: Sized {}`).
+            // See https://github.com/taiki-e/pin-project/issues/340.
             //
-            // This ensures that any unused type parameters
-            // don't end up with `Unpin` bounds.
-            let lifetime_fields = cx.orig.generics.lifetimes().enumerate().map(
-                |(i, LifetimeParam { lifetime, .. })| {
-                    let field_ident = format_ident!("__lifetime{}", i);
-                    quote!(#field_ident: &#lifetime ())
-                },
-            );
+            // Other types such as `PhantomPinned` cannot be used directly in the
+            // bounds, because the bounds may be trivially false, and the types
+            // may be private.
+            let pinned_type_params: Option<Vec<_>> =
+                cx.pinned_fields.iter().map(|ty| as_type_param(ty, cx.orig.generics)).collect();
+            let (impl_generics, unpin_struct) = if let Some(type_params) = pinned_type_params {
+                for (i, ident) in type_params.iter().enumerate() {
+                    if type_params[..i].contains(ident) {
+                        continue;
+                    }
+                    impl_where_clause.predicates.push(parse_quote! {
+                        _pin_project::__private::PinnedFieldsOf<#ident>:
+                            _pin_project::__private::Unpin
+                    });
+                }
+                (cx.orig.generics.split_for_impl().0, None)
+            } else {
+                // Generate a field in our new struct for every
+                // pinned field in the original type.
+                let fields = cx.pinned_fields.iter().enumerate().map(|(i, ty)| {
+                    let field_ident = format_ident!("__field{}", i);
+                    quote!(#field_ident: #ty)
+                });
+
+                // We could try to determine the subset of type parameters
+                // and lifetimes that are actually used by the pinned fields
+                // (as opposed to those only used by unpinned fields).
+                // However, this would be tricky and error-prone, since
+                // it's possible for users to create types that would alias
+                // with generic parameters (e.g. 'struct T').
+                //
+                // Instead, we generate a use of every single type parameter
+                // and lifetime used in the original struct. For type parameters,
+                // we generate code like this:
+                //
+                // ```
+                // struct AlwaysUnpin<T: ?Sized>(PhantomData<T>) {}
+                // impl<T: ?Sized> Unpin for AlwaysUnpin<T> {}
+                //
+                // ...
+                // _field: AlwaysUnpin<(A, B, C)>
+                // ```
+                //
+                // This ensures that any unused type parameters
+                // don't end up with `Unpin` bounds.
+                let lifetime_fields = cx.orig.generics.lifetimes().enumerate().map(
+                    |(i, LifetimeParam { lifetime, .. })| {
+                        let field_ident = format_ident!("__lifetime{}", i);
+                        quote!(#field_ident: &#lifetime ())
+                    },
+                );
+
+                let struct_ident = format_ident!("__{}", orig_ident);
+                let vis = cx.orig.vis;
+                let lifetime = &cx.proj.lifetime;
+                let type_params = cx.orig.generics.type_params().map(|t| &t.ident);
+                let proj_generics = &cx.proj.generics;
+                let (impl_generics, proj_ty_generics, _) = proj_generics.split_for_impl();
+
+                impl_where_clause.predicates.push(parse_quote! {
+                    _pin_project::__private::PinnedFieldsOf<#struct_ident #proj_ty_generics>:
+                        _pin_project::__private::Unpin
+                });
+                let unpin_struct = quote! {
+                    // This needs to have the same visibility as the original type,
+                    // due to the limitations of the 'public in private' error.
+                    //
+                    // Our goal is to implement the public trait `Unpin` for
+                    // a potentially public user type. Because of this, rust
+                    // requires that any types mentioned in the where clause of
+                    // our `Unpin` impl also be public. This means that our generated
+                    // `__UnpinStruct` type must also be public.
+                    // However, we ensure that the user can never actually reference
+                    // this 'public' type by creating this type in the inside of `const`.
+                    #[allow(missing_debug_implementations, unnameable_types)]
+                    #vis struct #struct_ident #proj_generics #ty_where_clause {
+                        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
+                            #lifetime, (#(_pin_project::__private::PhantomData<#type_params>),*)
+                        >,
+
+                        #(#fields,)*
+                        #(#lifetime_fields,)*
+                    }
+                };
+                (impl_generics, Some(unpin_struct))
+            };
 
-            let orig_ident = cx.orig.ident;
-            let struct_ident = format_ident!("__{}", orig_ident);
-            let vis = cx.orig.vis;
-            let lifetime = &cx.proj.lifetime;
-            let type_params = cx.orig.generics.type_params().map(|t| &t.ident);
-            let proj_generics = &cx.proj.generics;
-            let (impl_generics, proj_ty_generics, _) = proj_generics.split_for_impl();
-            let (_, ty_generics, ty_where_clause) = cx.orig.generics.split_for_impl();
+            // The additional predicates only narrow the `Unpin` impl, so they are
+            // always sound. They are not added to the dummy `UnsafeUnpin` impl,
+            // so that it still conflicts with any `UnsafeUnpin` impl written by
+            // the user.
+            let mut unpin_where_clause = impl_where_clause.clone();
+            if let Some(bounds) = &cx.unpin_bounds {
+                unpin_where_clause.predicates.extend(bounds.predicates.iter().cloned());
+            }
 
-            impl_where_clause.predicates.push(parse_quote! {
-                _pin_project::__private::PinnedFieldsOf<#struct_ident #proj_ty_generics>:
-                    _pin_project::__private::Unpin
+            // Fields with `#[pin(unsafe_exclude_from_unpin)]` attribute are not
+            // included in the bounds above. Unlike the other unsafe code in the
+            // generated code, this has the span of the argument, so
+            // `unsafe_code` lint treats the argument as unsafe code written by
+            // the user.
+            let excluded_from_unpin = cx.excluded_from_unpin.iter().map(|&span| {
+                quote_spanned! { span =>
+                    #[allow(unused_unsafe, clippy::undocumented_unsafe_blocks)]
+                    const _: () = unsafe {};
+                }
             });
 
             quote! {
-                // This needs to have the same visibility as the original type,
-                // due to the limitations of the 'public in private' error.
-                //
-                // Our goal is to implement the public trait `Unpin` for
-                // a potentially public user type. Because of this, rust
-                // requires that any types mentioned in the where clause of
-                // our `Unpin` impl also be public. This means that our generated
-                // `__UnpinStruct` type must also be public.
-                // However, we ensure that the user can never actually reference
-                // this 'public' type by creating this type in the inside of `const`.
-                #[allow(missing_debug_implementations, unnameable_types)]
-                #vis struct #struct_ident #proj_generics #ty_where_clause {
-                    __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
-                        #lifetime, (#(_pin_project::__private::PhantomData<#type_params>),*)
-                    >,
-
-                    #(#fields,)*
-                    #(#lifetime_fields,)*
-                }
+                #unpin_struct
+
+                #(#excluded_from_unpin)*
 
                 impl #impl_generics _pin_project::__private::Unpin for #orig_ident #ty_generics
-                #impl_where_clause
+                #unpin_where_clause
                 {
                 }
 
@@ -850,6 +1669,20 @@ fn make_unpin_impl(cx: &Context<'_>) -> TokenStream {
     }
 }
 
+/// Returns the identifier of the type parameter if the given type is a type
+/// parameter of the original type.
+fn as_type_param<'a>(ty: &'a Type, generics: &Generics) -> Option<&'a Ident> {
+    match ty {
+        Type::Group(ty) => as_type_param(&ty.elem, generics),
+        Type::Paren(ty) => as_type_param(&ty.elem, generics),
+        Type::Path(TypePath { qself: None, path }) => {
+            let ident = path.get_ident()?;
+            generics.type_params().any(|param| param.ident == *ident).then_some(ident)
+        }
+        _ => None,
+    }
+}
+
 #[allow(clippy::doc_overindented_list_items)]
 /// Creates `Drop` implementation for the original type.
 ///
@@ -865,6 +1698,20 @@ fn make_drop_impl(cx: &Context<'_>) -> TokenStream {
         // For interoperability with `forbid(unsafe_code)`, `unsafe` token should be
         // call-site span.
         let unsafety = <Token![unsafe]>::default();
+        let bind_pinned_drop_fn = cx.bind_pinned_drop_fn(span);
+        let call_pinned_drop = cx.call_pinned_drop(span, &quote_spanned!(span => __pinned_self));
+        // If `PinnedDrop = <path>` argument is used, generate a dummy impl of
+        // `PinnedDrop`, to ensure that the user cannot implement it.
+        let dummy_pinned_drop = cx.pinned_drop_fn.as_ref().map(|_| {
+            quote! {
+                #[doc(hidden)]
+                impl #impl_generics _pin_project::__private::PinnedDrop for #ident #ty_generics
+                #where_clause
+                {
+                    unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+                }
+            }
+        });
         quote_spanned! { span =>
             #[allow(clippy::missing_trait_methods)]
             impl #impl_generics _pin_project::__private::Drop for #ident #ty_generics
@@ -872,6 +1719,7 @@ fn make_drop_impl(cx: &Context<'_>) -> TokenStream {
             {
                 #[allow(clippy::missing_inline_in_public_items)]
                 fn drop(&mut self) {
+                    #bind_pinned_drop_fn
                     #unsafety {
                         // Safety - we're in 'drop', so we know that 'self' will
                         // never move again.
@@ -879,10 +1727,39 @@ fn make_drop_impl(cx: &Context<'_>) -> TokenStream {
                         // We call `pinned_drop` only once. Since `PinnedDrop::drop`
                         // is an unsafe method and a private API, it is never called again in safe
                         // code *unless the user uses a maliciously crafted macro*.
-                        _pin_project::__private::PinnedDrop::drop(__pinned_self);
+                        #call_pinned_drop;
                     }
                 }
             }
+            #dummy_pinned_drop
+        }
+    } else if !cx.variant_drops.is_empty() {
+        // For interoperability with `forbid(unsafe_code)`, `unsafe` token should be
+        // call-site span.
+        let unsafety = <Token![unsafe]>::default();
+        let call_variant_drops = cx.call_variant_drops(&quote! {
+            // Safety - we're in 'drop', so we know that 'self' will
+            // never move again.
+            #unsafety { _pin_project::__private::Pin::new_unchecked(self) }
+        });
+        quote! {
+            #[allow(clippy::missing_trait_methods)]
+            impl #impl_generics _pin_project::__private::Drop for #ident #ty_generics
+            #where_clause
+            {
+                #[allow(clippy::missing_inline_in_public_items)]
+                fn drop(&mut self) {
+                    #call_variant_drops
+                }
+            }
+
+            // Generate a dummy impl of `PinnedDrop`, to ensure that the user cannot implement it.
+            #[doc(hidden)]
+            impl #impl_generics _pin_project::__private::PinnedDrop for #ident #ty_generics
+            #where_clause
+            {
+                unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+            }
         }
     } else {
         // If the user does not provide a `PinnedDrop` impl,
@@ -944,6 +1821,7 @@ fn make_proj_impl(
     cx: &Context<'_>,
     proj_body: &TokenStream,
     proj_ref_body: &TokenStream,
+    proj_ptr_body: &TokenStream,
     proj_own_body: &TokenStream,
 ) -> TokenStream {
     let vis = &cx.proj.vis;
@@ -954,40 +1832,76 @@ fn make_proj_impl(
     let proj_own_ident = &cx.proj.own_ident;
 
     let orig_ty_generics = cx.orig.generics.split_for_impl().1;
-    let proj_ty_generics = cx.proj.generics.split_for_impl().1;
+    let proj_ty_generics = cx.proj_generics().0.split_for_impl().1;
     let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
     // TODO: For enums and project_replace, dead_code warnings should not be
     // allowed because methods are not generated unless explicitly specified.
     // However, there is currently no good way to allow warnings for generated
     // code, so we allow warnings for all methods for now.
-    let allow_dead_code = quote! { #[allow(dead_code)] };
+    let allow_dead_code = cx.allow_dead_code();
 
-    let mut project = Some(quote! {
-        #allow_dead_code
-        #[inline]
-        #vis fn project<#lifetime>(
-            self: _pin_project::__private::Pin<&#lifetime mut Self>,
-        ) -> #proj_ident #proj_ty_generics {
-            unsafe {
-                #proj_body
+    let mut project = cx.project_fn.ident("project").map(|project_fn| {
+        quote! {
+            #allow_dead_code
+            #[inline]
+            #vis fn #project_fn<#lifetime>(
+                self: _pin_project::__private::Pin<&#lifetime mut Self>,
+            ) -> #proj_ident #proj_ty_generics {
+                unsafe {
+                    #proj_body
+                }
             }
         }
     });
-    let mut project_ref = Some(quote! {
-        #allow_dead_code
-        #[inline]
-        #vis fn project_ref<#lifetime>(
-            self: _pin_project::__private::Pin<&#lifetime Self>,
-        ) -> #proj_ref_ident #proj_ty_generics {
-            unsafe {
-                #proj_ref_body
+    let mut project_ref = cx.project_ref_fn.ident("project_ref").map(|project_ref_fn| {
+        quote! {
+            #allow_dead_code
+            #[inline]
+            #vis fn #project_ref_fn<#lifetime>(
+                self: _pin_project::__private::Pin<&#lifetime Self>,
+            ) -> #proj_ref_ident #proj_ty_generics {
+                unsafe {
+                    #proj_ref_body
+                }
             }
         }
     });
-    let mut project_replace = cx.project_replace.span().map(|span| {
+    // Call `PinnedDrop::drop` or the drop hook of the active variant on the old
+    // value before moving its fields out. If it panics, the guard still drops
+    // all the fields, in the same way as when the `Drop` impl of the original
+    // type panics.
+    let pinned_self = quote!(_pin_project::__private::Pin::new_unchecked(&mut *__self_ptr));
+    let bind_pinned_drop_fn = cx.bind_pinned_drop_fn(Span::call_site());
+    let call_drop = if cx.pinned_drop.is_some() {
+        Some(cx.call_pinned_drop(Span::call_site(), &pinned_self))
+    } else if !cx.variant_drops.is_empty() {
+        Some(cx.call_variant_drops(&pinned_self))
+    } else {
+        None
+    };
+    let proj_own_body = if let Some(call_drop) = call_drop {
+        quote! {
+            let __proj_own = _pin_project::__private::UnsafeCallOnDropGuard::new(move || {
+                #proj_own_body
+            });
+            #call_drop;
+            (__proj_own.into_inner())()
+        }
+    } else {
+        proj_own_body.clone()
+    };
+    let project_replace_fn = cx.project_replace.span().map(|span| {
+        (
+            span,
+            cx.project_replace_fn
+                .clone()
+                .unwrap_or_else(|| format_ident!("project_replace", span = span)),
+        )
+    });
+    let mut project_replace = project_replace_fn.as_ref().map(|&(span, ref project_replace_fn)| {
         // It is enough to only set the span of the signature.
         let sig = quote_spanned! { span =>
-            #vis fn project_replace(
+            #vis fn #project_replace_fn(
                 self: _pin_project::__private::Pin<&mut Self>,
                 __replacement: Self,
             ) -> #proj_own_ident #orig_ty_generics
@@ -996,6 +1910,7 @@ fn make_proj_impl(
             #allow_dead_code
             #[inline]
             #sig {
+                #bind_pinned_drop_fn
                 unsafe {
                     let __self_ptr: *mut Self = self.get_unchecked_mut();
 
@@ -1011,6 +1926,88 @@ fn make_proj_impl(
             }
         }
     });
+    // The `Default` bound is higher-ranked so that it is not a trivially false
+    // bound (which is an error) on non-generic types that do not implement `Default`.
+    let project_take_fn = cx.project_take.zip(project_replace_fn.as_ref());
+    let mut project_take = project_take_fn.map(|(span, (_, project_replace_fn))| {
+        let sig = quote_spanned! { span =>
+            #vis fn project_take(
+                self: _pin_project::__private::Pin<&mut Self>,
+            ) -> #proj_own_ident #orig_ty_generics
+            where
+                for<#lifetime> Self: _pin_project::__private::Default
+        };
+        quote! {
+            #allow_dead_code
+            #[inline]
+            #sig {
+                self.#project_replace_fn(_pin_project::__private::Default::default())
+            }
+        }
+    });
+    // The closure takes the projected type with a higher-ranked lifetime so
+    // that it cannot keep the projection after the fields are moved out.
+    let proj_with_lifetime = if cx.proj.has_fields { Some(quote!(for<#lifetime>)) } else { None };
+    let mut project_replace_with = cx.project_replace_with.map(|span| {
+        let sig = quote_spanned! { span =>
+            #vis fn project_replace_with(
+                self: _pin_project::__private::Pin<&mut Self>,
+                __replacement: Self,
+                __f: impl #proj_with_lifetime _pin_project::__private::FnOnce(
+                    #proj_ident #proj_ty_generics,
+                ),
+            ) -> #proj_own_ident #orig_ty_generics
+        };
+        quote! {
+            #allow_dead_code
+            #[allow(clippy::impl_trait_in_params)]
+            #[inline]
+            #sig {
+                #bind_pinned_drop_fn
+                unsafe {
+                    let __self_ptr: *mut Self = self.get_unchecked_mut();
+
+                    // Give the closure access to the fields before any of them
+                    // are moved out or dropped.
+                    __f({ #proj_ptr_body });
+
+                    let __guard = _pin_project::__private::UnsafeOverwriteGuard::new(
+                        __self_ptr,
+                        __replacement,
+                    );
+
+                    #proj_own_body
+                }
+            }
+        }
+    });
+    let mut project_transition = cx.project_transition.map(|span| {
+        let proj_own_ty = quote!(#proj_own_ident #orig_ty_generics);
+        let sig = quote_spanned! { span =>
+            #vis fn project_transition(
+                self: _pin_project::__private::Pin<&mut Self>,
+                __f: impl _pin_project::__private::FnOnce(#proj_own_ty) -> Self,
+            )
+        };
+        quote! {
+            #allow_dead_code
+            #[allow(clippy::impl_trait_in_params)]
+            #[inline]
+            #sig {
+                #bind_pinned_drop_fn
+                unsafe {
+                    let __self_ptr: *mut Self = self.get_unchecked_mut();
+
+                    // `self` is left in an invalid state until the new value is written,
+                    // so this guard aborts the process if anything in between panics.
+                    let __guard = _pin_project::__private::UnsafeWriteOrAbortGuard::new(__self_ptr);
+
+                    let __proj_own = { #proj_own_body };
+                    __guard.write(__f(__proj_own));
+                }
+            }
+        }
+    });
 
     if cx.kind == Enum {
         if !cx.project {
@@ -1021,6 +2018,9 @@ fn make_proj_impl(
         }
         if cx.project_replace.ident().is_none() {
             project_replace = None;
+            project_take = None;
+            project_replace_with = None;
+            project_transition = None;
         }
     }
 
@@ -1029,8 +2029,366 @@ fn make_proj_impl(
             #project
             #project_ref
             #project_replace
+            #project_take
+            #project_replace_with
+            #project_transition
+        }
+    }
+}
+
+/// Creates an implementation of the `PinProject` trait if `PinProject` argument is
+/// specified.
+fn make_proj_trait_impl(
+    cx: &Context<'_>,
+    proj_body: &TokenStream,
+    proj_ref_body: &TokenStream,
+) -> TokenStream {
+    if cx.pin_project.is_none() {
+        return TokenStream::new();
+    }
+
+    let lifetime = &cx.proj.lifetime;
+    let orig_ident = cx.orig.ident;
+    let proj_ident = &cx.proj.mut_ident;
+    let proj_ref_ident = &cx.proj.ref_ident;
+    let proj_ty_generics = cx.proj_generics().0.split_for_impl().1;
+    let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics _pin_project::PinProject for #orig_ident #ty_generics #where_clause {
+            type Proj<#lifetime> = #proj_ident #proj_ty_generics where Self: #lifetime;
+            type ProjRef<#lifetime> = #proj_ref_ident #proj_ty_generics where Self: #lifetime;
+
+            #[inline]
+            fn project<#lifetime>(
+                self: _pin_project::__private::Pin<&#lifetime mut Self>,
+            ) -> Self::Proj<#lifetime> {
+                unsafe {
+                    #proj_body
+                }
+            }
+            #[inline]
+            fn project_ref<#lifetime>(
+                self: _pin_project::__private::Pin<&#lifetime Self>,
+            ) -> Self::ProjRef<#lifetime> {
+                unsafe {
+                    #proj_ref_body
+                }
+            }
+        }
+    }
+}
+
+/// Creates an implementation of the per-field accessor methods.
+///
+/// For each field, `<field>` and `<field>_ref` methods are generated, and for
+/// pinned fields, `set_<field>` method is also generated. Skipped fields have
+/// no accessor methods.
+fn make_accessors_impl(cx: &Context<'_>, fields: &FieldsNamed) -> Result<TokenStream> {
+    let lifetime = &cx.proj.lifetime;
+    let orig_ident = cx.orig.ident;
+    let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
+
+    // The names of the methods generated so far, and the fields for which
+    // they are generated (`None` for the other generated methods).
+    let mut method_names: Vec<(Ident, Option<&Ident>)> =
+        cx.method_names().into_iter().map(|name| (name, None)).collect();
+
+    let mut accessors = TokenStream::new();
+    for (i, Field { attrs, vis, ident, ty, .. }) in fields.named.iter().enumerate() {
+        let ident = ident.as_ref().unwrap();
+        let ref_ident = format_ident!("{}_ref", ident);
+        let set_ident = format_ident!("set_{}", ident);
+        let vis = determine_visibility(vis);
+        let FieldArgs { pinned, project, elements, skip, .. } = parse_field_args(attrs)?;
+        if skip.is_some() {
+            continue;
+        }
+
+        // Only the last field can be an unsized type, and setters cannot be
+        // generated for types that are never sized.
+        let is_last = i + 1 == fields.named.len();
+        let has_setter = pinned && !(is_last && is_unsized(ty));
+        let names = [Some(ident), Some(&ref_ident), has_setter.then_some(&set_ident)];
+        for name in names.into_iter().flatten() {
+            match method_names.iter().find(|(other, _)| other == name) {
+                Some((_, Some(other))) => bail!(
+                    ident,
+                    "accessor method `{}` for field `{}` conflicts with the accessor method for field `{}`; consider renaming one of the fields",
+                    name,
+                    ident,
+                    other,
+                ),
+                Some((_, None)) => bail!(
+                    ident,
+                    "accessor method `{}` for field `{}` conflicts with another method generated by #[pin_project] attribute; consider renaming the field",
+                    name,
+                    ident,
+                ),
+                None => method_names.push((name.clone(), Some(ident))),
+            }
+        }
+        if pinned {
+            if project.is_some() {
+                accessors.extend(quote! {
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime mut Self>,
+                    ) -> <#ty as _pin_project::PinProject>::Proj<#lifetime> {
+                        unsafe {
+                            _pin_project::PinProject::project(
+                                _pin_project::__private::Pin::new_unchecked(
+                                    &mut self.get_unchecked_mut().#ident,
+                                ),
+                            )
+                        }
+                    }
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ref_ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime Self>,
+                    ) -> <#ty as _pin_project::PinProject>::ProjRef<#lifetime> {
+                        unsafe {
+                            _pin_project::PinProject::project_ref(
+                                _pin_project::__private::Pin::new_unchecked(&self.get_ref().#ident),
+                            )
+                        }
+                    }
+                });
+            } else if elements.is_some() {
+                let elem = element_type(ty)?;
+                accessors.extend(quote! {
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime mut Self>,
+                    ) -> _pin_project::slice::PinSliceMut<#lifetime, #elem> {
+                        unsafe {
+                            _pin_project::slice::PinSliceMut::new(
+                                _pin_project::__private::Pin::new_unchecked(
+                                    &mut self.get_unchecked_mut().#ident,
+                                ),
+                            )
+                        }
+                    }
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ref_ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime Self>,
+                    ) -> _pin_project::slice::PinSliceRef<#lifetime, #elem> {
+                        unsafe {
+                            _pin_project::slice::PinSliceRef::new(
+                                _pin_project::__private::Pin::new_unchecked(&self.get_ref().#ident),
+                            )
+                        }
+                    }
+                });
+            } else {
+                accessors.extend(quote! {
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime mut Self>,
+                    ) -> _pin_project::__private::Pin<&#lifetime mut (#ty)> {
+                        unsafe {
+                            _pin_project::__private::Pin::new_unchecked(
+                                &mut self.get_unchecked_mut().#ident,
+                            )
+                        }
+                    }
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #ref_ident<#lifetime>(
+                        self: _pin_project::__private::Pin<&#lifetime Self>,
+                    ) -> _pin_project::__private::Pin<&#lifetime (#ty)> {
+                        unsafe {
+                            _pin_project::__private::Pin::new_unchecked(&self.get_ref().#ident)
+                        }
+                    }
+                });
+            }
+            if !has_setter {
+                continue;
+            }
+            // The last field may be an unsized type parameter, so we add a
+            // `Sized` bound to its setter.
+            let sized_bound = if is_last {
+                Some(quote!(where #ty: _pin_project::__private::Sized))
+            } else {
+                None
+            };
+            accessors.extend(quote! {
+                #[allow(dead_code)]
+                #[inline]
+                #vis fn #set_ident(self: _pin_project::__private::Pin<&mut Self>, value: #ty)
+                #sized_bound
+                {
+                    unsafe {
+                        _pin_project::__private::Pin::new_unchecked(
+                            &mut self.get_unchecked_mut().#ident,
+                        )
+                    }
+                    .set(value);
+                }
+            });
+        } else {
+            accessors.extend(quote! {
+                #[allow(dead_code)]
+                #[inline]
+                #vis fn #ident<#lifetime>(
+                    self: _pin_project::__private::Pin<&#lifetime mut Self>,
+                ) -> &#lifetime mut (#ty) {
+                    unsafe { &mut self.get_unchecked_mut().#ident }
+                }
+                #[allow(dead_code)]
+                #[inline]
+                #vis fn #ref_ident<#lifetime>(
+                    self: _pin_project::__private::Pin<&#lifetime Self>,
+                ) -> &#lifetime (#ty) {
+                    &self.get_ref().#ident
+                }
+            });
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics #orig_ident #ty_generics #where_clause {
+            #accessors
+        }
+    })
+}
+
+/// Creates the type returned by the `into_parts` method and an implementation
+/// of the method, and returns them separately.
+///
+/// The type has the same shape as the original type, but without `#[pin]`
+/// attributes or a `Drop` impl, and the method moves all the fields,
+/// including pinned and skipped fields, into it.
+fn make_into_parts(
+    cx: &Context<'_>,
+    parts_ident: &Ident,
+    variants: &[(Option<&Ident>, &Fields)],
+) -> (TokenStream, TokenStream) {
+    let vis = &cx.proj.vis;
+    let orig_ident = cx.orig.ident;
+    let mut orig_generics = cx.orig.generics.clone();
+    let orig_where_clause = orig_generics.where_clause.take();
+    let (impl_generics, ty_generics, where_clause) = cx.orig.generics.split_for_impl();
+    let allow_dead_code = cx.allow_dead_code();
+
+    let mut parts_variants = TokenStream::new();
+    let mut parts_arms = TokenStream::new();
+    for &(variant_ident, fields) in variants {
+        let delim = match fields {
+            Fields::Named(_) => Delimiter::Brace,
+            Fields::Unnamed(_) | Fields::Unit => Delimiter::Parenthesis,
+        };
+        let mut parts_fields = TokenStream::new();
+        let mut pat = TokenStream::new();
+        let mut body = TokenStream::new();
+        for (i, Field { vis, ident, colon_token, ty, .. }) in fields.iter().enumerate() {
+            let binding = ident.clone().unwrap_or_else(|| format_ident!("_{}", i));
+            parts_fields.extend(quote!(#vis #ident #colon_token #ty,));
+            pat.extend(quote!(#binding,));
+            body.extend(quote! {
+                #ident #colon_token _pin_project::__private::ptr::read(#binding),
+            });
+        }
+        let (parts_fields, pat, body) = match fields {
+            Fields::Unit => (None, None, None),
+            _ => (
+                Some(Group::new(delim, parts_fields)),
+                Some(Group::new(delim, pat)),
+                Some(Group::new(delim, body)),
+            ),
+        };
+        match variant_ident {
+            Some(variant_ident) => {
+                parts_variants.extend(quote!(#variant_ident #parts_fields,));
+                parts_arms.extend(quote! {
+                    Self::#variant_ident #pat => #parts_ident::#variant_ident #body,
+                });
+            }
+            None => {
+                let parts_fields = match fields {
+                    Fields::Named(_) => quote!(#orig_where_clause #parts_fields),
+                    Fields::Unnamed(_) | Fields::Unit => {
+                        quote!(#parts_fields #orig_where_clause;)
+                    }
+                };
+                parts_variants.extend(parts_fields);
+                parts_arms.extend(quote! {
+                    Self #pat => #parts_ident #body,
+                });
+            }
         }
     }
+
+    let global_allowed_lints = global_allowed_lints();
+    let pub_allowed_lints = cx.pub_allowed_lints();
+    let (item, variant_size_differences) = match cx.kind {
+        Struct => (quote!(struct #parts_ident #orig_generics #parts_variants), None),
+        Enum => (
+            quote! {
+                enum #parts_ident #orig_generics #orig_where_clause {
+                    #parts_variants
+                }
+            },
+            Some(quote! { variant_size_differences, clippy::large_enum_variant, }),
+        ),
+    };
+
+    let parts_type = quote! {
+        #[allow(
+            dead_code, // This lint warns unused fields/variants.
+            #global_allowed_lints
+            #pub_allowed_lints
+            #variant_size_differences
+            clippy::missing_docs_in_private_items
+        )]
+        #vis #item
+    };
+    let parts_impl = quote! {
+        impl #impl_generics #orig_ident #ty_generics #where_clause {
+            #allow_dead_code
+            #[inline]
+            #vis fn into_parts(self) -> #parts_ident #ty_generics {
+                // Do not call the destructor of the original type. The fields
+                // are moved out instead of being dropped.
+                let __this = _pin_project::__private::ManuallyDrop::new(self);
+                unsafe {
+                    match &*__this {
+                        #parts_arms
+                    }
+                }
+            }
+        }
+    };
+    (parts_type, parts_impl)
+}
+
+/// Returns `true` if the given type is a type that is never sized: `str`,
+/// slices, and trait objects.
+fn is_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::Group(ty) => is_unsized(&ty.elem),
+        Type::Paren(ty) => is_unsized(&ty.elem),
+        Type::Path(ty) => ty.qself.is_none() && ty.path.is_ident("str"),
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns the element type of the given array or slice type.
+fn element_type(ty: &Type) -> Result<&Type> {
+    match ty {
+        Type::Group(ty) => element_type(&ty.elem),
+        Type::Paren(ty) => element_type(&ty.elem),
+        Type::Array(ty) => Ok(&ty.elem),
+        Type::Slice(ty) => Ok(&ty.elem),
+        _ => bail!(ty, "`elements` argument may only be used on fields of array or slice types"),
+    }
 }
 
 /// Checks that the `[repr(packed)]` attribute is not included.
@@ -1,22 +1,25 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens as _, format_ident, quote};
+use quote::{ToTokens as _, format_ident, quote, quote_spanned};
 use syn::{
     Error, FnArg, GenericArgument, Ident, ImplItem, ItemImpl, Pat, PatIdent, PatType, Path,
     PathArguments, Result, ReturnType, Signature, Token, Type, TypePath, TypeReference,
-    parse_quote, spanned::Spanned as _, token::Colon, visit_mut::VisitMut as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token::Colon,
+    visit_mut::VisitMut as _,
 };
 
-use crate::utils::{ReplaceReceiver, SliceExt as _};
+use crate::utils::{ReplaceReceiver, SliceExt as _, parse_crate_path};
 
 pub(crate) fn attribute(args: &TokenStream, mut input: ItemImpl) -> TokenStream {
+    let mut crate_path = None;
     let res = (|| -> Result<()> {
-        if !args.is_empty() {
-            bail!(args, "unexpected argument: `{}`", args)
-        }
+        crate_path = syn::parse2::<Args>(args.clone())?.crate_path;
         validate_impl(&input)?;
-        expand_impl(&mut input);
+        expand_impl(&mut input, crate_path.as_ref());
         Ok(())
     })();
 
@@ -37,11 +40,15 @@ pub(crate) fn attribute(args: &TokenStream, mut input: ItemImpl) -> TokenStream
             // However, if `input.self_ty` is not Type::Path, there is a high possibility that
             // the type does not exist (since #[pin_project] can only be used on struct/enum
             // definitions), so do not generate a dummy impl.
+            let crate_path = match &crate_path {
+                Some(path) => path.to_token_stream(),
+                None => quote!(::pin_project),
+            };
             tokens.extend(quote! {
-                impl #impl_generics ::pin_project::__private::PinnedDrop for #self_ty
+                impl #impl_generics #crate_path::__private::PinnedDrop for #self_ty
                 #where_clause
                 {
-                    unsafe fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
+                    unsafe fn drop(self: #crate_path::__private::Pin<&mut Self>) {}
                 }
             });
         }
@@ -51,6 +58,34 @@ pub(crate) fn attribute(args: &TokenStream, mut input: ItemImpl) -> TokenStream
     }
 }
 
+struct Args {
+    /// `crate = <path>` argument.
+    crate_path: Option<Path>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut crate_path = None;
+
+        while !input.is_empty() {
+            if input.peek(Token![crate]) {
+                let crate_token: Token![crate] = input.parse()?;
+                crate_path = Some(parse_crate_path(input, crate_token, crate_path.is_some())?);
+            } else {
+                let args: TokenStream = input.parse()?;
+                bail!(args, "unexpected argument: `{}`", args);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(Self { crate_path })
+    }
+}
+
 /// Validates the signature of given `PinnedDrop` impl.
 fn validate_impl(item: &ItemImpl) -> Result<()> {
     const INVALID_ITEM: &str =
@@ -171,13 +206,18 @@ fn validate_sig(sig: &Signature) -> Result<()> {
 //     __drop_inner(self);
 // }
 //
-fn expand_impl(item: &mut ItemImpl) {
+fn expand_impl(item: &mut ItemImpl, crate_path: Option<&Path>) {
     // `PinnedDrop` is a private trait and should not appear in docs.
     item.attrs.push(parse_quote!(#[doc(hidden)]));
 
     let path = &mut item.trait_.as_mut().expect("unexpected inherent impl").1;
-    *path = parse_quote_spanned! { Span::call_site().located_at(path.span()) =>
-        ::pin_project::__private::PinnedDrop
+    let span = Span::call_site().located_at(path.span());
+    let crate_path = match crate_path {
+        Some(path) => path.to_token_stream(),
+        None => quote_spanned!(span=> ::pin_project),
+    };
+    *path = parse_quote_spanned! { span =>
+        #crate_path::__private::PinnedDrop
     };
 
     let ImplItem::Fn(method) = &mut item.items[0] else { unreachable!() };
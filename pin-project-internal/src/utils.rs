@@ -86,6 +86,47 @@ pub(crate) fn determine_visibility(vis: &Visibility) -> Visibility {
     }
 }
 
+/// Converts the name of a variant to snake case, e.g., `HttpRequest` and
+/// `HTTPRequest` to `http_request`.
+pub(crate) fn to_snake_case(ident: &Ident) -> String {
+    let s = ident.to_string();
+    let chars: Vec<char> = s.strip_prefix("r#").unwrap_or(&s).chars().collect();
+    let mut out = String::with_capacity(chars.len() + 4);
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && i != 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(ch.to_lowercase());
+    }
+    out
+}
+
+/// Parses `= <path>` in `crate = <path>` argument.
+pub(crate) fn parse_crate_path(
+    input: ParseStream<'_>,
+    crate_token: Token![crate],
+    has_prev: bool,
+) -> Result<Path> {
+    if input.is_empty() {
+        bail!(crate_token, "expected `crate = <path>`, found `crate`");
+    }
+    let eq_token: Token![=] = input.parse()?;
+    if input.is_empty() {
+        let span = quote!(#crate_token #eq_token);
+        bail!(span, "expected `crate = <path>`, found `crate =`");
+    }
+    let path = input.call(Path::parse_mod_style)?;
+    if has_prev {
+        let span = quote!(#crate_token #path);
+        bail!(span, "duplicate `crate` argument");
+    }
+    Ok(path)
+}
+
 pub(crate) fn respan<T>(node: &T, span: Span) -> T
 where
     T: ToTokens + Parse,
@@ -109,29 +150,10 @@ fn respan_tokens(tokens: TokenStream, span: Span) -> TokenStream {
 // extension traits
 
 pub(crate) trait SliceExt {
-    fn position_exact(&self, ident: &str) -> Result<Option<usize>>;
     fn find(&self, ident: &str) -> Option<&Attribute>;
 }
 
 impl SliceExt for [Attribute] {
-    /// # Errors
-    ///
-    /// - There are multiple specified attributes.
-    /// - The `Attribute::tokens` field of the specified attribute is not empty.
-    fn position_exact(&self, ident: &str) -> Result<Option<usize>> {
-        self.iter()
-            .try_fold((0, None), |(i, mut prev), attr| {
-                if attr.path().is_ident(ident) {
-                    if prev.replace(i).is_some() {
-                        bail!(attr, "duplicate #[{}] attribute", ident);
-                    }
-                    attr.meta.require_path_only()?;
-                }
-                Ok((i + 1, prev))
-            })
-            .map(|(_, pos)| pos)
-    }
-
     fn find(&self, ident: &str) -> Option<&Attribute> {
         self.iter().position(|attr| attr.path().is_ident(ident)).map(|i| &self[i])
     }
@@ -114,6 +114,8 @@ see [examples] directory for more examples and generated code.
 )]
 #![allow(clippy::needless_doctest_main)]
 
+use core::pin::Pin;
+
 #[doc(inline)]
 pub use pin_project_internal::pin_project;
 #[doc(inline)]
@@ -182,15 +184,95 @@ pub use pin_project_internal::pinned_drop;
 /// [undefined-behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
 pub unsafe trait UnsafeUnpin {}
 
+/// A trait implemented by types with the [`#[pin_project]`][macro@pin_project]
+/// attribute and `PinProject` argument.
+///
+/// This trait provides the same projections as the `project` and `project_ref`
+/// methods generated by the [`#[pin_project]`][macro@pin_project] attribute, and
+/// allows writing code that is generic over pin-projectable types, or naming
+/// the projected types in signatures.
+///
+/// This trait is only implemented if the `PinProject` argument is passed, since
+/// it exposes the projected types with the visibility of the original type. If
+/// the original type is `pub` and the projected types are named by the
+/// `project` or `project_ref` arguments, `project_vis = pub` argument is also
+/// required. The `PinProject` argument cannot be used with the `!project` and
+/// `!project_ref` arguments.
+///
+/// Fields of types that implement this trait can be projected through with
+/// `#[pin(project)]` attribute.
+///
+/// ```
+/// use pin_project::pin_project;
+///
+/// #[pin_project(PinProject, project = InnerProj, project_vis = pub)]
+/// pub struct Inner<T> {
+///     #[pin]
+///     pinned: T,
+/// }
+///
+/// #[pin_project]
+/// pub struct Outer<T> {
+///     #[pin(project)]
+///     inner: Inner<T>,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::{PinProject, pin_project};
+///
+/// #[pin_project(PinProject)]
+/// struct Struct<T> {
+///     #[pin]
+///     pinned: T,
+///     unpinned: usize,
+/// }
+///
+/// fn project<T: PinProject>(x: Pin<&mut T>) -> T::Proj<'_> {
+///     x.project()
+/// }
+///
+/// let mut x = Box::pin(Struct { pinned: 1, unpinned: 2 });
+/// let this = project(x.as_mut());
+/// let _: Pin<&mut i32> = this.pinned;
+/// assert_eq!(*this.unpinned, 2);
+/// ```
+pub trait PinProject {
+    /// The projected type returned by the [`project`](Self::project) method.
+    type Proj<'pin>
+    where
+        Self: 'pin;
+    /// The projected type returned by the [`project_ref`](Self::project_ref) method.
+    type ProjRef<'pin>
+    where
+        Self: 'pin;
+
+    /// Creates the pinned reference to the pinned fields and the normal
+    /// reference to the other fields.
+    fn project<'pin>(self: Pin<&'pin mut Self>) -> Self::Proj<'pin>;
+
+    /// Creates the pinned shared reference to the pinned fields and the normal
+    /// shared reference to the other fields.
+    fn project_ref<'pin>(self: Pin<&'pin Self>) -> Self::ProjRef<'pin>;
+}
+
+pub mod slice;
+
 // Not public API.
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub mod __private {
-    use core::mem::ManuallyDrop;
     #[doc(hidden)]
     pub use core::{
-        marker::{PhantomData, PhantomPinned, Unpin},
-        ops::Drop,
+        default::Default,
+        marker::{PhantomData, PhantomPinned, Sized, Unpin},
+        mem::ManuallyDrop,
+        ops::{Drop, FnOnce},
+        option::Option,
         pin::Pin,
         ptr,
     };
@@ -349,4 +431,62 @@ pub mod __private {
             }
         }
     }
+
+    // This is an internal helper used to ensure a closure is called even if
+    // the code between `new` and `into_inner` panics.
+    #[doc(hidden)]
+    pub struct UnsafeCallOnDropGuard<F: FnOnce() -> R, R> {
+        f: ManuallyDrop<F>,
+        _marker: PhantomData<fn() -> R>,
+    }
+    impl<F: FnOnce() -> R, R> UnsafeCallOnDropGuard<F, R> {
+        #[doc(hidden)]
+        pub unsafe fn new(f: F) -> Self {
+            Self { f: ManuallyDrop::new(f), _marker: PhantomData }
+        }
+        #[doc(hidden)]
+        pub fn into_inner(self) -> F {
+            let mut this = ManuallyDrop::new(self);
+            // SAFETY: `this` is never dropped, so `f` is never taken again.
+            unsafe { ManuallyDrop::take(&mut this.f) }
+        }
+    }
+    impl<F: FnOnce() -> R, R> Drop for UnsafeCallOnDropGuard<F, R> {
+        fn drop(&mut self) {
+            // SAFETY: the caller of `UnsafeCallOnDropGuard::new` must guarantee
+            // that `f` is safe to call when this guard is destructed.
+            unsafe {
+                ManuallyDrop::take(&mut self.f)();
+            }
+        }
+    }
+    // This is an internal helper used to write a value to a location that is
+    // left in an invalid state, and to abort the process if the code between
+    // `new` and `write` panics, since there is no valid value to write.
+    #[doc(hidden)]
+    pub struct UnsafeWriteOrAbortGuard<T> {
+        target: *mut T,
+    }
+    impl<T> UnsafeWriteOrAbortGuard<T> {
+        #[doc(hidden)]
+        pub unsafe fn new(target: *mut T) -> Self {
+            Self { target }
+        }
+        #[doc(hidden)]
+        pub fn write(self, value: T) {
+            let this = ManuallyDrop::new(self);
+            // SAFETY: the caller of `UnsafeWriteOrAbortGuard::new` must guarantee
+            // that `target` is valid for writes.
+            unsafe {
+                ptr::write(this.target, value);
+            }
+        }
+    }
+    impl<T> Drop for UnsafeWriteOrAbortGuard<T> {
+        fn drop(&mut self) {
+            // This is only reached while unwinding, and panicking while
+            // unwinding aborts the process.
+            panic!("pin-project: a panic occurred while the value was in an invalid state");
+        }
+    }
 }
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Pinned views of slices, used by fields with `#[pin(elements)]` attribute.
+//!
+//! The elements of a pinned array or slice are structurally pinned, so the
+//! types in this module provide pinned references to each element of a
+//! pinned slice without `unsafe` code.
+
+use core::{fmt, pin::Pin, slice};
+
+/// A pinned mutable view of a slice that provides pinned references to its
+/// elements.
+///
+/// This is the type of the fields with `#[pin(elements)]` attribute in the
+/// projected type returned by the `project` method.
+///
+/// # Examples
+///
+/// ```
+/// use std::pin::Pin;
+///
+/// use pin_project::slice::PinSliceMut;
+///
+/// let mut array = [1, 2, 3];
+/// let mut slice = PinSliceMut::new(Pin::new(&mut array[..]));
+/// for x in slice.iter_mut() {
+///     let _: Pin<&mut i32> = x;
+/// }
+/// slice.get_mut(0).unwrap().set(4);
+/// assert_eq!(array, [4, 2, 3]);
+/// ```
+pub struct PinSliceMut<'a, T> {
+    slice: Pin<&'a mut [T]>,
+}
+
+impl<'a, T> PinSliceMut<'a, T> {
+    /// Creates a new `PinSliceMut` from a pinned mutable reference to a slice.
+    #[must_use]
+    #[inline]
+    pub fn new(slice: Pin<&'a mut [T]>) -> Self {
+        Self { slice }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns a pinned mutable reference to the element at `index`, or
+    /// `None` if `index` is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<Pin<&mut T>> {
+        // SAFETY: the elements of a pinned slice are structurally pinned.
+        unsafe {
+            self.slice.as_mut().get_unchecked_mut().get_mut(index).map(|x| Pin::new_unchecked(x))
+        }
+    }
+
+    /// Returns a pinned shared reference to the element at `index`, or
+    /// `None` if `index` is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Pin<&T>> {
+        self.as_ref().into_get(index)
+    }
+
+    /// Returns an iterator over pinned mutable references to the elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.as_mut().into_iter()
+    }
+
+    /// Returns an iterator over pinned shared references to the elements.
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_ref().into_iter()
+    }
+
+    /// Reborrows the view for a shorter lifetime.
+    #[must_use]
+    #[inline]
+    pub fn as_mut(&mut self) -> PinSliceMut<'_, T> {
+        PinSliceMut { slice: self.slice.as_mut() }
+    }
+
+    /// Returns a shared view of the slice.
+    #[must_use]
+    #[inline]
+    pub fn as_ref(&self) -> PinSliceRef<'_, T> {
+        PinSliceRef { slice: self.slice.as_ref() }
+    }
+
+    /// Consumes the view and returns the pinned mutable reference to the
+    /// slice.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Pin<&'a mut [T]> {
+        self.slice
+    }
+}
+
+impl<'a, T> IntoIterator for PinSliceMut<'a, T> {
+    type Item = Pin<&'a mut T>;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // SAFETY: the elements are only exposed as pinned references.
+        IterMut { iter: unsafe { self.slice.get_unchecked_mut() }.iter_mut() }
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut PinSliceMut<'_, T> {
+    type Item = Pin<&'b mut T>;
+    type IntoIter = IterMut<'b, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'b, T> IntoIterator for &'b PinSliceMut<'_, T> {
+    type Item = Pin<&'b T>;
+    type IntoIter = Iter<'b, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> From<Pin<&'a mut [T]>> for PinSliceMut<'a, T> {
+    #[inline]
+    fn from(slice: Pin<&'a mut [T]>) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PinSliceMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.slice, f)
+    }
+}
+
+/// A pinned shared view of a slice that provides pinned references to its
+/// elements.
+///
+/// This is the type of the fields with `#[pin(elements)]` attribute in the
+/// projected type returned by the `project_ref` method.
+pub struct PinSliceRef<'a, T> {
+    slice: Pin<&'a [T]>,
+}
+
+impl<'a, T> PinSliceRef<'a, T> {
+    /// Creates a new `PinSliceRef` from a pinned shared reference to a slice.
+    #[must_use]
+    #[inline]
+    pub fn new(slice: Pin<&'a [T]>) -> Self {
+        Self { slice }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns a pinned shared reference to the element at `index`, or
+    /// `None` if `index` is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Pin<&'a T>> {
+        self.into_get(index)
+    }
+
+    /// Returns an iterator over pinned shared references to the elements.
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        (*self).into_iter()
+    }
+
+    /// Returns the pinned shared reference to the slice.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Pin<&'a [T]> {
+        self.slice
+    }
+
+    fn into_get(self, index: usize) -> Option<Pin<&'a T>> {
+        // SAFETY: the elements of a pinned slice are structurally pinned.
+        unsafe { self.slice.get_ref().get(index).map(|x| Pin::new_unchecked(x)) }
+    }
+}
+
+impl<T> Clone for PinSliceRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PinSliceRef<'_, T> {}
+
+impl<'a, T> IntoIterator for PinSliceRef<'a, T> {
+    type Item = Pin<&'a T>;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { iter: self.slice.get_ref().iter() }
+    }
+}
+
+impl<'a, T> IntoIterator for &PinSliceRef<'a, T> {
+    type Item = Pin<&'a T>;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> From<Pin<&'a [T]>> for PinSliceRef<'a, T> {
+    #[inline]
+    fn from(slice: Pin<&'a [T]>) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PinSliceRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.slice, f)
+    }
+}
+
+/// An iterator over pinned mutable references to the elements of a slice.
+///
+/// This is created by [`PinSliceMut::iter_mut`] method.
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    iter: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = Pin<&'a mut T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: this iterator is created from a pinned slice.
+        self.iter.next().map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: this iterator is created from a pinned slice.
+        self.iter.next_back().map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// An iterator over pinned shared references to the elements of a slice.
+///
+/// This is created by [`PinSliceRef::iter`] method.
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    iter: slice::Iter<'a, T>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = Pin<&'a T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: this iterator is created from a pinned slice.
+        self.iter.next().map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: this iterator is created from a pinned slice.
+        self.iter.next_back().map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
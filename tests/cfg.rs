@@ -125,6 +125,41 @@ fn cfg() {
     let _ = Field::TupleVariant(Always);
 }
 
+#[test]
+fn cfg_all_fields() {
+    #[pin_project(project_replace)]
+    struct Struct {
+        #[cfg(any())]
+        #[pin]
+        f: Never,
+    }
+
+    assert_unpin!(Struct);
+
+    let mut x = Struct {};
+    let _ = Pin::new(&mut x).project();
+    let _ = Pin::new(&x).project_ref();
+    let _ = Pin::new(&mut x).project_replace(Struct {});
+
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef, project_replace = EnumProjOwn)]
+    enum Enum {
+        V {
+            #[cfg(any())]
+            #[pin]
+            f: Never,
+        },
+        #[cfg(any())]
+        N(#[pin] Never),
+    }
+
+    assert_unpin!(Enum);
+
+    let mut x = Enum::V {};
+    let EnumProj::V {} = Pin::new(&mut x).project();
+    let EnumProjRef::V {} = Pin::new(&x).project_ref();
+    let EnumProjOwn::V {} = Pin::new(&mut x).project_replace(Enum::V {});
+}
+
 #[test]
 fn cfg_attr() {
     #[pin_project(project_replace)]
@@ -15,9 +15,11 @@ enum Enum<T, U> {
     clippy::unknown_clippy_lints,
     clippy::absolute_paths,
     clippy::min_ident_chars,
+    clippy::partial_pub_fields,
     clippy::pattern_type_mismatch,
     clippy::pub_with_shorthand,
     clippy::redundant_pub_crate,
+    clippy::same_name_method,
     clippy::single_char_lifetime_names,
     clippy::type_repetition_in_bounds,
     clippy::missing_docs_in_private_items,
@@ -44,9 +46,11 @@ where
     clippy::unknown_clippy_lints,
     clippy::absolute_paths,
     clippy::min_ident_chars,
+    clippy::partial_pub_fields,
     clippy::pattern_type_mismatch,
     clippy::pub_with_shorthand,
     clippy::redundant_pub_crate,
+    clippy::same_name_method,
     clippy::single_char_lifetime_names,
     clippy::type_repetition_in_bounds,
     clippy::missing_docs_in_private_items,
@@ -70,9 +74,11 @@ where
     clippy::unknown_clippy_lints,
     clippy::absolute_paths,
     clippy::min_ident_chars,
+    clippy::partial_pub_fields,
     clippy::pattern_type_mismatch,
     clippy::pub_with_shorthand,
     clippy::redundant_pub_crate,
+    clippy::same_name_method,
     clippy::single_char_lifetime_names,
     clippy::type_repetition_in_bounds,
     clippy::elidable_lifetime_names,
@@ -133,30 +139,14 @@ const _: () = {
             }
         }
     }
-    #[allow(missing_debug_implementations, unnameable_types)]
-    struct __Enum<'pin, T, U> {
-        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
-            'pin,
-            (
-                _pin_project::__private::PhantomData<T>,
-                _pin_project::__private::PhantomData<U>,
-            ),
-        >,
-        __field0: T,
-        __field1: T,
-    }
-    impl<'pin, T, U> _pin_project::__private::Unpin for Enum<T, U>
+    impl<T, U> _pin_project::__private::Unpin for Enum<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __Enum<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     #[doc(hidden)]
-    unsafe impl<'pin, T, U> _pin_project::UnsafeUnpin for Enum<T, U>
+    unsafe impl<T, U> _pin_project::UnsafeUnpin for Enum<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __Enum<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     trait EnumMustNotImplDrop {}
     #[allow(clippy::drop_bounds, drop_bounds)]
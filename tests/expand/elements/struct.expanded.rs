@@ -0,0 +1,121 @@
+use pin_project::pin_project;
+#[pin(__private())]
+struct Struct<T, U, const N: usize> {
+    #[pin(elements)]
+    array: [T; N],
+    unpinned: U,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::mut_mut)]
+    struct __StructProjection<'pin, T, U, const N: usize>
+    where
+        Struct<T, U, N>: 'pin,
+    {
+        array: ::pin_project::slice::PinSliceMut<'pin, T>,
+        unpinned: &'pin mut (U),
+    }
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::ref_option_ref)]
+    struct __StructProjectionRef<'pin, T, U, const N: usize>
+    where
+        Struct<T, U, N>: 'pin,
+    {
+        array: ::pin_project::slice::PinSliceRef<'pin, T>,
+        unpinned: &'pin (U),
+    }
+    impl<T, U, const N: usize> Struct<T, U, N> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> __StructProjection<'pin, T, U, N> {
+            unsafe {
+                let Self { array, unpinned } = self.get_unchecked_mut();
+                __StructProjection {
+                    array: _pin_project::slice::PinSliceMut::new(
+                        _pin_project::__private::Pin::new_unchecked(array),
+                    ),
+                    unpinned,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> __StructProjectionRef<'pin, T, U, N> {
+            unsafe {
+                let Self { array, unpinned } = self.get_ref();
+                __StructProjectionRef {
+                    array: _pin_project::slice::PinSliceRef::new(
+                        _pin_project::__private::Pin::new_unchecked(array),
+                    ),
+                    unpinned,
+                }
+            }
+        }
+    }
+    #[forbid(unaligned_references, safe_packed_borrows)]
+    fn __assert_not_repr_packed<T, U, const N: usize>(this: &Struct<T, U, N>) {
+        let _ = &this.array;
+        let _ = &this.unpinned;
+    }
+    #[allow(missing_debug_implementations, unnameable_types)]
+    struct __Struct<'pin, T, U, const N: usize> {
+        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
+            'pin,
+            (
+                _pin_project::__private::PhantomData<T>,
+                _pin_project::__private::PhantomData<U>,
+            ),
+        >,
+        __field0: [T; N],
+    }
+    impl<'pin, T, U, const N: usize> _pin_project::__private::Unpin for Struct<T, U, N>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __Struct<'pin, T, U, N>,
+        >: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<'pin, T, U, const N: usize> _pin_project::UnsafeUnpin for Struct<T, U, N>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __Struct<'pin, T, U, N>,
+        >: _pin_project::__private::Unpin,
+    {}
+    trait StructMustNotImplDrop {}
+    #[allow(clippy::drop_bounds, drop_bounds)]
+    impl<T: _pin_project::__private::Drop> StructMustNotImplDrop for T {}
+    impl<T, U, const N: usize> StructMustNotImplDrop for Struct<T, U, N> {}
+    #[doc(hidden)]
+    impl<T, U, const N: usize> _pin_project::__private::PinnedDrop for Struct<T, U, N> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+fn main() {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pin_project::pin_project;
+
+#[pin_project]
+struct Struct<T, U, const N: usize> {
+    #[pin(elements)]
+    array: [T; N],
+    unpinned: U,
+}
+
+fn main() {}
@@ -0,0 +1,122 @@
+use std::pin::Pin;
+use pin_project::{pin_project, pinned_drop};
+#[pin(__private(PinnedDrop, into_parts = EnumParts))]
+enum Enum<T, U> {
+    Struct { #[pin] pinned: T, unpinned: U },
+    Tuple(#[pin] T, U),
+    Unit,
+}
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    variant_size_differences,
+    clippy::large_enum_variant,
+    clippy::missing_docs_in_private_items
+)]
+enum EnumParts<T, U> {
+    Struct { pinned: T, unpinned: U },
+    Tuple(T, U),
+    Unit,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    impl<T, U> Enum<T, U> {}
+    impl<T, U> Enum<T, U> {
+        #[allow(dead_code)]
+        #[inline]
+        fn into_parts(self) -> EnumParts<T, U> {
+            let __this = _pin_project::__private::ManuallyDrop::new(self);
+            unsafe {
+                match &*__this {
+                    Self::Struct { pinned, unpinned } => {
+                        EnumParts::Struct {
+                            pinned: _pin_project::__private::ptr::read(pinned),
+                            unpinned: _pin_project::__private::ptr::read(unpinned),
+                        }
+                    }
+                    Self::Tuple(_0, _1) => {
+                        EnumParts::Tuple(
+                            _pin_project::__private::ptr::read(_0),
+                            _pin_project::__private::ptr::read(_1),
+                        )
+                    }
+                    Self::Unit => EnumParts::Unit,
+                }
+            }
+        }
+    }
+    impl<T, U> _pin_project::__private::Unpin for Enum<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<T, U> _pin_project::UnsafeUnpin for Enum<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    #[allow(clippy::missing_trait_methods)]
+    impl<T, U> _pin_project::__private::Drop for Enum<T, U> {
+        #[allow(clippy::missing_inline_in_public_items)]
+        fn drop(&mut self) {
+            unsafe {
+                let __pinned_self = _pin_project::__private::Pin::new_unchecked(self);
+                _pin_project::__private::PinnedDrop::drop(__pinned_self);
+            }
+        }
+    }
+};
+#[doc(hidden)]
+impl<T, U> ::pin_project::__private::PinnedDrop for Enum<T, U> {
+    unsafe fn drop(self: Pin<&mut Self>) {
+        #[allow(
+            clippy::missing_const_for_fn,
+            clippy::needless_pass_by_value,
+            clippy::single_call_fn
+        )]
+        fn __drop_inner<T, U>(__self: Pin<&mut Enum<T, U>>) {
+            fn __drop_inner() {}
+            let _ = __self;
+        }
+        __drop_inner(self);
+    }
+}
+fn main() {}
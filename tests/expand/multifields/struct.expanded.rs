@@ -18,9 +18,11 @@ struct Struct<T, U> {
     clippy::unknown_clippy_lints,
     clippy::absolute_paths,
     clippy::min_ident_chars,
+    clippy::partial_pub_fields,
     clippy::pattern_type_mismatch,
     clippy::pub_with_shorthand,
     clippy::redundant_pub_crate,
+    clippy::same_name_method,
     clippy::single_char_lifetime_names,
     clippy::type_repetition_in_bounds,
     clippy::elidable_lifetime_names,
@@ -130,30 +132,14 @@ const _: () = {
         let _ = &this.unpinned1;
         let _ = &this.unpinned2;
     }
-    #[allow(missing_debug_implementations, unnameable_types)]
-    struct __Struct<'pin, T, U> {
-        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
-            'pin,
-            (
-                _pin_project::__private::PhantomData<T>,
-                _pin_project::__private::PhantomData<U>,
-            ),
-        >,
-        __field0: T,
-        __field1: T,
-    }
-    impl<'pin, T, U> _pin_project::__private::Unpin for Struct<T, U>
+    impl<T, U> _pin_project::__private::Unpin for Struct<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __Struct<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     #[doc(hidden)]
-    unsafe impl<'pin, T, U> _pin_project::UnsafeUnpin for Struct<T, U>
+    unsafe impl<T, U> _pin_project::UnsafeUnpin for Struct<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __Struct<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     trait StructMustNotImplDrop {}
     #[allow(clippy::drop_bounds, drop_bounds)]
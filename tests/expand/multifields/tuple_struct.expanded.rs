@@ -11,9 +11,11 @@ struct TupleStruct<T, U>(#[pin] T, #[pin] T, U, U);
     clippy::unknown_clippy_lints,
     clippy::absolute_paths,
     clippy::min_ident_chars,
+    clippy::partial_pub_fields,
     clippy::pattern_type_mismatch,
     clippy::pub_with_shorthand,
     clippy::redundant_pub_crate,
+    clippy::same_name_method,
     clippy::single_char_lifetime_names,
     clippy::type_repetition_in_bounds,
     clippy::elidable_lifetime_names,
@@ -120,30 +122,14 @@ const _: () = {
         let _ = &this.2;
         let _ = &this.3;
     }
-    #[allow(missing_debug_implementations, unnameable_types)]
-    struct __TupleStruct<'pin, T, U> {
-        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
-            'pin,
-            (
-                _pin_project::__private::PhantomData<T>,
-                _pin_project::__private::PhantomData<U>,
-            ),
-        >,
-        __field0: T,
-        __field1: T,
-    }
-    impl<'pin, T, U> _pin_project::__private::Unpin for TupleStruct<T, U>
+    impl<T, U> _pin_project::__private::Unpin for TupleStruct<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __TupleStruct<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     #[doc(hidden)]
-    unsafe impl<'pin, T, U> _pin_project::UnsafeUnpin for TupleStruct<T, U>
+    unsafe impl<T, U> _pin_project::UnsafeUnpin for TupleStruct<T, U>
     where
-        _pin_project::__private::PinnedFieldsOf<
-            __TupleStruct<'pin, T, U>,
-        >: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
     {}
     trait TupleStructMustNotImplDrop {}
     #[allow(clippy::drop_bounds, drop_bounds)]
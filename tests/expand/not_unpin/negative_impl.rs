@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(negative_impls)]
+
+use pin_project::pin_project;
+
+#[pin_project(!Unpin, negative_impl)]
+struct Struct<T, U> {
+    #[pin]
+    pinned: T,
+    unpinned: U,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::pin::Pin;
+
+use pin_project::pin_project;
+
+#[pin_project(PinnedDrop = drop_struct)]
+struct Struct<T, U> {
+    #[pin]
+    pinned: T,
+    unpinned: U,
+}
+
+fn drop_struct<T, U>(this: Pin<&mut Struct<T, U>>) {
+    let _ = this;
+}
+
+fn main() {}
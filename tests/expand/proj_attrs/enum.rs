@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pin_project::pin_project;
+
+#[pin_project(
+    project = EnumProj,
+    project_ref = EnumProjRef,
+    project_replace = EnumProjOwn,
+    project_attr(derive(Debug)),
+    project_ref_attr(derive(Clone, Copy)),
+    project_replace_attr(must_use)
+)]
+enum Enum<T, U> {
+    #[pin(project_attr(non_exhaustive))]
+    Struct {
+        #[pin]
+        pinned: T,
+        #[pin(project_ref_attr(doc(hidden)), project_replace_attr(doc(hidden)))]
+        unpinned: U,
+    },
+    Tuple(#[pin] T, U),
+    Unit,
+}
+
+fn main() {}
@@ -0,0 +1,255 @@
+use pin_project::pin_project;
+#[pin(__private(PinProject))]
+struct Inner<T> {
+    #[pin]
+    pinned: T,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    #[allow(
+        dead_code,
+        missing_debug_implementations,
+        unnameable_types,
+        clippy::pub_underscore_fields,
+        clippy::missing_docs_in_private_items,
+        clippy::mut_mut
+    )]
+    struct __InnerProjection<'pin, T>
+    where
+        Inner<T>: 'pin,
+    {
+        pinned: ::pin_project::__private::Pin<&'pin mut (T)>,
+    }
+    #[allow(
+        dead_code,
+        missing_debug_implementations,
+        unnameable_types,
+        clippy::pub_underscore_fields,
+        clippy::missing_docs_in_private_items,
+        clippy::ref_option_ref
+    )]
+    struct __InnerProjectionRef<'pin, T>
+    where
+        Inner<T>: 'pin,
+    {
+        pinned: ::pin_project::__private::Pin<&'pin (T)>,
+    }
+    impl<T> Inner<T> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> __InnerProjection<'pin, T> {
+            unsafe {
+                let Self { pinned } = self.get_unchecked_mut();
+                __InnerProjection {
+                    pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> __InnerProjectionRef<'pin, T> {
+            unsafe {
+                let Self { pinned } = self.get_ref();
+                __InnerProjectionRef {
+                    pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                }
+            }
+        }
+    }
+    impl<T> _pin_project::PinProject for Inner<T> {
+        type Proj<'pin> = __InnerProjection<'pin, T> where Self: 'pin;
+        type ProjRef<'pin> = __InnerProjectionRef<'pin, T> where Self: 'pin;
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> Self::Proj<'pin> {
+            unsafe {
+                let Self { pinned } = self.get_unchecked_mut();
+                __InnerProjection {
+                    pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                }
+            }
+        }
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> Self::ProjRef<'pin> {
+            unsafe {
+                let Self { pinned } = self.get_ref();
+                __InnerProjectionRef {
+                    pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                }
+            }
+        }
+    }
+    #[forbid(unaligned_references, safe_packed_borrows)]
+    fn __assert_not_repr_packed<T>(this: &Inner<T>) {
+        let _ = &this.pinned;
+    }
+    impl<T> _pin_project::__private::Unpin for Inner<T>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<T> _pin_project::UnsafeUnpin for Inner<T>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    trait InnerMustNotImplDrop {}
+    #[allow(clippy::drop_bounds, drop_bounds)]
+    impl<T: _pin_project::__private::Drop> InnerMustNotImplDrop for T {}
+    impl<T> InnerMustNotImplDrop for Inner<T> {}
+    #[doc(hidden)]
+    impl<T> _pin_project::__private::PinnedDrop for Inner<T> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+#[pin(__private())]
+struct Struct<T, U> {
+    #[pin(project)]
+    inner: Inner<T>,
+    unpinned: U,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::mut_mut)]
+    struct __StructProjection<'pin, T, U>
+    where
+        Struct<T, U>: 'pin,
+    {
+        inner: <Inner<T> as ::pin_project::PinProject>::Proj<'pin>,
+        unpinned: &'pin mut (U),
+    }
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::ref_option_ref)]
+    struct __StructProjectionRef<'pin, T, U>
+    where
+        Struct<T, U>: 'pin,
+    {
+        inner: <Inner<T> as ::pin_project::PinProject>::ProjRef<'pin>,
+        unpinned: &'pin (U),
+    }
+    impl<T, U> Struct<T, U> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> __StructProjection<'pin, T, U> {
+            unsafe {
+                let Self { inner, unpinned } = self.get_unchecked_mut();
+                __StructProjection {
+                    inner: _pin_project::PinProject::project(
+                        _pin_project::__private::Pin::new_unchecked(inner),
+                    ),
+                    unpinned,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> __StructProjectionRef<'pin, T, U> {
+            unsafe {
+                let Self { inner, unpinned } = self.get_ref();
+                __StructProjectionRef {
+                    inner: _pin_project::PinProject::project_ref(
+                        _pin_project::__private::Pin::new_unchecked(inner),
+                    ),
+                    unpinned,
+                }
+            }
+        }
+    }
+    #[forbid(unaligned_references, safe_packed_borrows)]
+    fn __assert_not_repr_packed<T, U>(this: &Struct<T, U>) {
+        let _ = &this.inner;
+        let _ = &this.unpinned;
+    }
+    #[allow(missing_debug_implementations, unnameable_types)]
+    struct __Struct<'pin, T, U> {
+        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
+            'pin,
+            (
+                _pin_project::__private::PhantomData<T>,
+                _pin_project::__private::PhantomData<U>,
+            ),
+        >,
+        __field0: Inner<T>,
+    }
+    impl<'pin, T, U> _pin_project::__private::Unpin for Struct<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __Struct<'pin, T, U>,
+        >: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<'pin, T, U> _pin_project::UnsafeUnpin for Struct<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __Struct<'pin, T, U>,
+        >: _pin_project::__private::Unpin,
+    {}
+    trait StructMustNotImplDrop {}
+    #[allow(clippy::drop_bounds, drop_bounds)]
+    impl<T: _pin_project::__private::Drop> StructMustNotImplDrop for T {}
+    impl<T, U> StructMustNotImplDrop for Struct<T, U> {}
+    #[doc(hidden)]
+    impl<T, U> _pin_project::__private::PinnedDrop for Struct<T, U> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+fn main() {}
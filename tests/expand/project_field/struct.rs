@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pin_project::pin_project;
+
+#[pin_project(PinProject)]
+struct Inner<T> {
+    #[pin]
+    pinned: T,
+}
+
+#[pin_project]
+struct Struct<T, U> {
+    #[pin(project)]
+    inner: Inner<T>,
+    unpinned: U,
+}
+
+fn main() {}
@@ -0,0 +1,497 @@
+use pin_project::pin_project;
+#[pin(__private(project_replace = EnumProjOwn, project_variants))]
+enum Enum<T, U> {
+    #[pin(drop = drop_struct)]
+    Struct { #[pin] pinned: T, unpinned: U },
+    #[pin(drop = Self::drop_tuple)]
+    Tuple(#[pin] T, U),
+    Unit,
+}
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    variant_size_differences,
+    clippy::large_enum_variant,
+    clippy::missing_docs_in_private_items
+)]
+enum EnumProjOwn<T, U> {
+    Struct { pinned: ::pin_project::__private::PhantomData<T>, unpinned: U },
+    Tuple(::pin_project::__private::PhantomData<T>, U),
+    Unit,
+}
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumStructProj<'pin, T, U>
+where
+    Enum<T, U>: 'pin,
+{
+    pinned: ::pin_project::__private::Pin<&'pin mut (T)>,
+    unpinned: &'pin mut (U),
+    __pin_project_phantom: ::pin_project::__private::PhantomData<&'pin mut Enum<T, U>>,
+}
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumStructProjRef<'pin, T, U>
+where
+    Enum<T, U>: 'pin,
+{
+    pinned: ::pin_project::__private::Pin<&'pin (T)>,
+    unpinned: &'pin (U),
+    __pin_project_phantom: ::pin_project::__private::PhantomData<&'pin Enum<T, U>>,
+}
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumTupleProj<'pin, T, U>(
+    ::pin_project::__private::Pin<&'pin mut (T)>,
+    &'pin mut (U),
+    ::pin_project::__private::PhantomData<&'pin mut Enum<T, U>>,
+)
+where
+    Enum<T, U>: 'pin;
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumTupleProjRef<'pin, T, U>(
+    ::pin_project::__private::Pin<&'pin (T)>,
+    &'pin (U),
+    ::pin_project::__private::PhantomData<&'pin Enum<T, U>>,
+)
+where
+    Enum<T, U>: 'pin;
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumUnitProj<'pin, T, U>(
+    ::pin_project::__private::PhantomData<&'pin mut Enum<T, U>>,
+)
+where
+    Enum<T, U>: 'pin;
+#[allow(
+    dead_code,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::missing_docs_in_private_items,
+    clippy::mut_mut,
+    clippy::pub_underscore_fields,
+    clippy::ref_option_ref
+)]
+struct EnumUnitProjRef<'pin, T, U>(
+    ::pin_project::__private::PhantomData<&'pin Enum<T, U>>,
+)
+where
+    Enum<T, U>: 'pin;
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    impl<T, U> Enum<T, U> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project_replace(
+            self: _pin_project::__private::Pin<&mut Self>,
+            __replacement: Self,
+        ) -> EnumProjOwn<T, U> {
+            unsafe {
+                let __self_ptr: *mut Self = self.get_unchecked_mut();
+                let __guard = _pin_project::__private::UnsafeOverwriteGuard::new(
+                    __self_ptr,
+                    __replacement,
+                );
+                let __proj_own = _pin_project::__private::UnsafeCallOnDropGuard::new(move ||
+                {
+                    match &mut *__self_ptr {
+                        Self::Struct { pinned, unpinned } => {
+                            let __result = EnumProjOwn::Struct {
+                                pinned: _pin_project::__private::PhantomData,
+                                unpinned: _pin_project::__private::ptr::read(unpinned),
+                            };
+                            {
+                                let __guard = _pin_project::__private::UnsafeDropInPlaceGuard::new(
+                                    pinned,
+                                );
+                            }
+                            __result
+                        }
+                        Self::Tuple(_0, _1) => {
+                            let __result = EnumProjOwn::Tuple(
+                                _pin_project::__private::PhantomData,
+                                _pin_project::__private::ptr::read(_1),
+                            );
+                            {
+                                let __guard = _pin_project::__private::UnsafeDropInPlaceGuard::new(
+                                    _0,
+                                );
+                            }
+                            __result
+                        }
+                        Self::Unit => {
+                            let __result = EnumProjOwn::Unit;
+                            {}
+                            __result
+                        }
+                    }
+                });
+                {
+                    let mut __pinned_self: _pin_project::__private::Pin<&mut Self> = _pin_project::__private::Pin::new_unchecked(
+                        &mut *__self_ptr,
+                    );
+                    if let _pin_project::__private::Option::Some(__this) = __pinned_self
+                        .as_mut()
+                        .project_struct()
+                    {
+                        drop_struct(__this);
+                    }
+                    if let _pin_project::__private::Option::Some(__this) = __pinned_self
+                        .as_mut()
+                        .project_tuple()
+                    {
+                        Self::drop_tuple(__this);
+                    }
+                };
+                (__proj_own.into_inner())()
+            }
+        }
+    }
+    impl<T, U> Enum<T, U> {
+        #[allow(dead_code)]
+        #[inline]
+        fn is_struct(&self) -> bool {
+            #[allow(unreachable_patterns)]
+            match self {
+                Self::Struct { .. } => true,
+                _ => false,
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_struct<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> _pin_project::__private::Option<EnumStructProj<'pin, T, U>> {
+            unsafe {
+                match self.get_unchecked_mut() {
+                    Self::Struct { pinned, unpinned } => {
+                        _pin_project::__private::Option::Some(EnumStructProj {
+                            pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                            unpinned,
+                            __pin_project_phantom: _pin_project::__private::PhantomData,
+                        })
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_struct_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> _pin_project::__private::Option<EnumStructProjRef<'pin, T, U>> {
+            unsafe {
+                match self.get_ref() {
+                    Self::Struct { pinned, unpinned } => {
+                        _pin_project::__private::Option::Some(EnumStructProjRef {
+                            pinned: _pin_project::__private::Pin::new_unchecked(pinned),
+                            unpinned,
+                            __pin_project_phantom: _pin_project::__private::PhantomData,
+                        })
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn is_tuple(&self) -> bool {
+            #[allow(unreachable_patterns)]
+            match self {
+                Self::Tuple { .. } => true,
+                _ => false,
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_tuple<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> _pin_project::__private::Option<EnumTupleProj<'pin, T, U>> {
+            unsafe {
+                match self.get_unchecked_mut() {
+                    Self::Tuple(_0, _1) => {
+                        _pin_project::__private::Option::Some(
+                            EnumTupleProj(
+                                _pin_project::__private::Pin::new_unchecked(_0),
+                                _1,
+                                _pin_project::__private::PhantomData,
+                            ),
+                        )
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_tuple_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> _pin_project::__private::Option<EnumTupleProjRef<'pin, T, U>> {
+            unsafe {
+                match self.get_ref() {
+                    Self::Tuple(_0, _1) => {
+                        _pin_project::__private::Option::Some(
+                            EnumTupleProjRef(
+                                _pin_project::__private::Pin::new_unchecked(_0),
+                                _1,
+                                _pin_project::__private::PhantomData,
+                            ),
+                        )
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn is_unit(&self) -> bool {
+            #[allow(unreachable_patterns)]
+            match self {
+                Self::Unit { .. } => true,
+                _ => false,
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_unit<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> _pin_project::__private::Option<EnumUnitProj<'pin, T, U>> {
+            unsafe {
+                match self.get_unchecked_mut() {
+                    Self::Unit => {
+                        _pin_project::__private::Option::Some(
+                            EnumUnitProj(_pin_project::__private::PhantomData),
+                        )
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[allow(unreachable_patterns)]
+        #[inline]
+        fn project_unit_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> _pin_project::__private::Option<EnumUnitProjRef<'pin, T, U>> {
+            unsafe {
+                match self.get_ref() {
+                    Self::Unit => {
+                        _pin_project::__private::Option::Some(
+                            EnumUnitProjRef(_pin_project::__private::PhantomData),
+                        )
+                    }
+                    _ => _pin_project::__private::Option::None,
+                }
+            }
+        }
+    }
+    impl<T, U> _pin_project::__private::Unpin for Enum<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<T, U> _pin_project::UnsafeUnpin for Enum<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+    {}
+    #[allow(clippy::missing_trait_methods)]
+    impl<T, U> _pin_project::__private::Drop for Enum<T, U> {
+        #[allow(clippy::missing_inline_in_public_items)]
+        fn drop(&mut self) {
+            {
+                let mut __pinned_self: _pin_project::__private::Pin<&mut Self> = unsafe {
+                    _pin_project::__private::Pin::new_unchecked(self)
+                };
+                if let _pin_project::__private::Option::Some(__this) = __pinned_self
+                    .as_mut()
+                    .project_struct()
+                {
+                    drop_struct(__this);
+                }
+                if let _pin_project::__private::Option::Some(__this) = __pinned_self
+                    .as_mut()
+                    .project_tuple()
+                {
+                    Self::drop_tuple(__this);
+                }
+            }
+        }
+    }
+    #[doc(hidden)]
+    impl<T, U> _pin_project::__private::PinnedDrop for Enum<T, U> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+fn drop_struct<T, U>(this: EnumStructProj<'_, T, U>) {
+    let _ = this;
+}
+impl<T, U> Enum<T, U> {
+    fn drop_tuple(this: EnumTupleProj<'_, T, U>) {
+        let _ = this;
+    }
+}
+fn main() {}
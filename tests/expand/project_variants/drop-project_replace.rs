@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pin_project::pin_project;
+
+#[pin_project(project_replace = EnumProjOwn, project_variants)]
+enum Enum<T, U> {
+    #[pin(drop = drop_struct)]
+    Struct {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    },
+    #[pin(drop = Self::drop_tuple)]
+    Tuple(#[pin] T, U),
+    Unit,
+}
+
+fn drop_struct<T, U>(this: EnumStructProj<'_, T, U>) {
+    let _ = this;
+}
+
+impl<T, U> Enum<T, U> {
+    fn drop_tuple(this: EnumTupleProj<'_, T, U>) {
+        let _ = this;
+    }
+}
+
+fn main() {}
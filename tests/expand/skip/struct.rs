@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::marker::PhantomData;
+
+use pin_project::pin_project;
+
+#[pin_project(project_replace)]
+struct Struct<T, U> {
+    #[pin]
+    pinned: T,
+    unpinned: U,
+    #[pin(skip)]
+    marker: PhantomData<fn() -> U>,
+}
+
+fn main() {}
@@ -0,0 +1,245 @@
+use std::marker::PhantomPinned;
+use pin_project::pin_project;
+#[pin(__private())]
+struct TypeParams<T, U, V> {
+    #[pin]
+    pinned1: T,
+    #[pin]
+    pinned2: U,
+    #[pin]
+    pinned3: T,
+    unpinned: V,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::mut_mut)]
+    struct __TypeParamsProjection<'pin, T, U, V>
+    where
+        TypeParams<T, U, V>: 'pin,
+    {
+        pinned1: ::pin_project::__private::Pin<&'pin mut (T)>,
+        pinned2: ::pin_project::__private::Pin<&'pin mut (U)>,
+        pinned3: ::pin_project::__private::Pin<&'pin mut (T)>,
+        unpinned: &'pin mut (V),
+    }
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::ref_option_ref)]
+    struct __TypeParamsProjectionRef<'pin, T, U, V>
+    where
+        TypeParams<T, U, V>: 'pin,
+    {
+        pinned1: ::pin_project::__private::Pin<&'pin (T)>,
+        pinned2: ::pin_project::__private::Pin<&'pin (U)>,
+        pinned3: ::pin_project::__private::Pin<&'pin (T)>,
+        unpinned: &'pin (V),
+    }
+    impl<T, U, V> TypeParams<T, U, V> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> __TypeParamsProjection<'pin, T, U, V> {
+            unsafe {
+                let Self { pinned1, pinned2, pinned3, unpinned } = self
+                    .get_unchecked_mut();
+                __TypeParamsProjection {
+                    pinned1: _pin_project::__private::Pin::new_unchecked(pinned1),
+                    pinned2: _pin_project::__private::Pin::new_unchecked(pinned2),
+                    pinned3: _pin_project::__private::Pin::new_unchecked(pinned3),
+                    unpinned,
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> __TypeParamsProjectionRef<'pin, T, U, V> {
+            unsafe {
+                let Self { pinned1, pinned2, pinned3, unpinned } = self.get_ref();
+                __TypeParamsProjectionRef {
+                    pinned1: _pin_project::__private::Pin::new_unchecked(pinned1),
+                    pinned2: _pin_project::__private::Pin::new_unchecked(pinned2),
+                    pinned3: _pin_project::__private::Pin::new_unchecked(pinned3),
+                    unpinned,
+                }
+            }
+        }
+    }
+    #[forbid(unaligned_references, safe_packed_borrows)]
+    fn __assert_not_repr_packed<T, U, V>(this: &TypeParams<T, U, V>) {
+        let _ = &this.pinned1;
+        let _ = &this.pinned2;
+        let _ = &this.pinned3;
+        let _ = &this.unpinned;
+    }
+    impl<T, U, V> _pin_project::__private::Unpin for TypeParams<T, U, V>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<U>: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<T, U, V> _pin_project::UnsafeUnpin for TypeParams<T, U, V>
+    where
+        _pin_project::__private::PinnedFieldsOf<T>: _pin_project::__private::Unpin,
+        _pin_project::__private::PinnedFieldsOf<U>: _pin_project::__private::Unpin,
+    {}
+    trait TypeParamsMustNotImplDrop {}
+    #[allow(clippy::drop_bounds, drop_bounds)]
+    impl<T: _pin_project::__private::Drop> TypeParamsMustNotImplDrop for T {}
+    impl<T, U, V> TypeParamsMustNotImplDrop for TypeParams<T, U, V> {}
+    #[doc(hidden)]
+    impl<T, U, V> _pin_project::__private::PinnedDrop for TypeParams<T, U, V> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+#[pin(__private())]
+struct OtherTypes<T, U> {
+    #[pin]
+    pinned1: T,
+    #[pin]
+    pinned2: Option<U>,
+    #[pin]
+    pinned3: PhantomPinned,
+}
+#[allow(
+    unused_qualifications,
+    deprecated,
+    explicit_outlives_requirements,
+    single_use_lifetimes,
+    unreachable_pub,
+    unused_tuple_struct_fields,
+    clippy::unknown_clippy_lints,
+    clippy::absolute_paths,
+    clippy::min_ident_chars,
+    clippy::partial_pub_fields,
+    clippy::pattern_type_mismatch,
+    clippy::pub_with_shorthand,
+    clippy::redundant_pub_crate,
+    clippy::same_name_method,
+    clippy::single_char_lifetime_names,
+    clippy::type_repetition_in_bounds,
+    clippy::elidable_lifetime_names,
+    clippy::missing_const_for_fn,
+    clippy::needless_lifetimes,
+    clippy::semicolon_if_nothing_returned,
+    clippy::use_self,
+    clippy::used_underscore_binding
+)]
+const _: () = {
+    #[allow(unused_extern_crates)]
+    extern crate pin_project as _pin_project;
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::mut_mut)]
+    struct __OtherTypesProjection<'pin, T, U>
+    where
+        OtherTypes<T, U>: 'pin,
+    {
+        pinned1: ::pin_project::__private::Pin<&'pin mut (T)>,
+        pinned2: ::pin_project::__private::Pin<&'pin mut (Option<U>)>,
+        pinned3: ::pin_project::__private::Pin<&'pin mut (PhantomPinned)>,
+    }
+    #[allow(dead_code, clippy::missing_docs_in_private_items, clippy::ref_option_ref)]
+    struct __OtherTypesProjectionRef<'pin, T, U>
+    where
+        OtherTypes<T, U>: 'pin,
+    {
+        pinned1: ::pin_project::__private::Pin<&'pin (T)>,
+        pinned2: ::pin_project::__private::Pin<&'pin (Option<U>)>,
+        pinned3: ::pin_project::__private::Pin<&'pin (PhantomPinned)>,
+    }
+    impl<T, U> OtherTypes<T, U> {
+        #[allow(dead_code)]
+        #[inline]
+        fn project<'pin>(
+            self: _pin_project::__private::Pin<&'pin mut Self>,
+        ) -> __OtherTypesProjection<'pin, T, U> {
+            unsafe {
+                let Self { pinned1, pinned2, pinned3 } = self.get_unchecked_mut();
+                __OtherTypesProjection {
+                    pinned1: _pin_project::__private::Pin::new_unchecked(pinned1),
+                    pinned2: _pin_project::__private::Pin::new_unchecked(pinned2),
+                    pinned3: _pin_project::__private::Pin::new_unchecked(pinned3),
+                }
+            }
+        }
+        #[allow(dead_code)]
+        #[inline]
+        fn project_ref<'pin>(
+            self: _pin_project::__private::Pin<&'pin Self>,
+        ) -> __OtherTypesProjectionRef<'pin, T, U> {
+            unsafe {
+                let Self { pinned1, pinned2, pinned3 } = self.get_ref();
+                __OtherTypesProjectionRef {
+                    pinned1: _pin_project::__private::Pin::new_unchecked(pinned1),
+                    pinned2: _pin_project::__private::Pin::new_unchecked(pinned2),
+                    pinned3: _pin_project::__private::Pin::new_unchecked(pinned3),
+                }
+            }
+        }
+    }
+    #[forbid(unaligned_references, safe_packed_borrows)]
+    fn __assert_not_repr_packed<T, U>(this: &OtherTypes<T, U>) {
+        let _ = &this.pinned1;
+        let _ = &this.pinned2;
+        let _ = &this.pinned3;
+    }
+    #[allow(missing_debug_implementations, unnameable_types)]
+    struct __OtherTypes<'pin, T, U> {
+        __pin_project_use_generics: _pin_project::__private::AlwaysUnpin<
+            'pin,
+            (
+                _pin_project::__private::PhantomData<T>,
+                _pin_project::__private::PhantomData<U>,
+            ),
+        >,
+        __field0: T,
+        __field1: Option<U>,
+        __field2: PhantomPinned,
+    }
+    impl<'pin, T, U> _pin_project::__private::Unpin for OtherTypes<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __OtherTypes<'pin, T, U>,
+        >: _pin_project::__private::Unpin,
+    {}
+    #[doc(hidden)]
+    unsafe impl<'pin, T, U> _pin_project::UnsafeUnpin for OtherTypes<T, U>
+    where
+        _pin_project::__private::PinnedFieldsOf<
+            __OtherTypes<'pin, T, U>,
+        >: _pin_project::__private::Unpin,
+    {}
+    trait OtherTypesMustNotImplDrop {}
+    #[allow(clippy::drop_bounds, drop_bounds)]
+    impl<T: _pin_project::__private::Drop> OtherTypesMustNotImplDrop for T {}
+    impl<T, U> OtherTypesMustNotImplDrop for OtherTypes<T, U> {}
+    #[doc(hidden)]
+    impl<T, U> _pin_project::__private::PinnedDrop for OtherTypes<T, U> {
+        unsafe fn drop(self: _pin_project::__private::Pin<&mut Self>) {}
+    }
+};
+fn main() {}
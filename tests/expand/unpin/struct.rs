@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::marker::PhantomPinned;
+
+use pin_project::pin_project;
+
+#[pin_project]
+struct TypeParams<T, U, V> {
+    #[pin]
+    pinned1: T,
+    #[pin]
+    pinned2: U,
+    #[pin]
+    pinned3: T,
+    unpinned: V,
+}
+
+#[pin_project]
+struct OtherTypes<T, U> {
+    #[pin]
+    pinned1: T,
+    #[pin]
+    pinned2: Option<U>,
+    #[pin]
+    pinned3: PhantomPinned,
+}
+
+fn main() {}
@@ -3,11 +3,12 @@
 use pin_project::pin_project;
 
 #[pin_project]
-struct S {
-    //~^ ERROR may not be used on structs with zero fields
-    #[cfg(any())]
+struct Struct<T, U> {
+    #[pin(unsafe_exclude_from_unpin)]
+    excluded: T,
     #[pin]
-    f: u8,
+    pinned: T,
+    unpinned: U,
 }
 
 fn main() {}
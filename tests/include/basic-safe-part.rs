@@ -273,3 +273,153 @@ pub enum NotUnpinEnum<T, U> {
     /// Unit variant.
     Unit,
 }
+
+/// Testing struct with accessors.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(accessors)]
+#[derive(Debug)]
+pub struct AccessorsStruct<T, U> {
+    /// Pinned field.
+    #[pin]
+    pub pinned: T,
+    /// Unpinned field.
+    pub unpinned: U,
+}
+
+/// Testing struct with `PinProject` argument.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(PinProject)]
+#[derive(Debug)]
+pub struct PinProjectStruct<T, U> {
+    /// Pinned field.
+    #[pin]
+    pub pinned: T,
+    /// Unpinned field.
+    pub unpinned: U,
+}
+
+/// Testing named enum with `PinProject` argument.
+#[allow(clippy::exhaustive_enums)] // for the type itself
+#[::pin_project::pin_project(
+    PinProject,
+    project = PinProjectEnumProj,
+    project_ref = PinProjectEnumProjRef,
+    project_vis = pub,
+)]
+#[derive(Debug)]
+pub enum PinProjectEnum<T, U> {
+    /// Tuple variant.
+    Tuple(#[pin] T, U),
+    /// Unit variant.
+    Unit,
+}
+
+/// Testing struct with projected field.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project]
+#[derive(Debug)]
+pub struct ProjectFieldStruct<T, U> {
+    /// Projected field.
+    #[pin(project)]
+    pub inner: PinProjectStruct<T, U>,
+    /// Unpinned field.
+    pub unpinned: U,
+}
+
+/// Testing struct with skipped fields.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(project_replace)]
+#[derive(Debug)]
+pub struct SkipStruct<T, U> {
+    /// Pinned field.
+    #[pin]
+    #[pin(skip)]
+    pub pinned: T,
+    /// Unpinned field.
+    #[pin(skip)]
+    pub unpinned: U,
+    /// Unpinned field.
+    pub field: u8,
+}
+
+/// Testing struct with public projected types.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(
+    project = ProjectVisStructProj,
+    project_ref = ProjectVisStructProjRef,
+    project_replace = ProjectVisStructProjOwn,
+    project_vis = pub,
+)]
+#[derive(Debug)]
+pub struct ProjectVisStruct<T, U> {
+    /// Pinned field.
+    #[pin]
+    pub pinned: T,
+    /// Unpinned field.
+    pub unpinned: U,
+}
+
+/// Testing unit struct.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(project_replace)]
+#[derive(Debug, Clone, Copy)]
+pub struct UnitStruct;
+
+/// Testing enum without fields.
+#[allow(clippy::exhaustive_enums)] // for the type itself
+#[::pin_project::pin_project(
+    project = UnitEnumProj,
+    project_ref = UnitEnumProjRef,
+    project_replace = UnitEnumProjOwn,
+)]
+#[derive(Debug, Clone, Copy)]
+pub enum UnitEnum {
+    /// Unit variant.
+    First,
+    /// Unit variant.
+    Second,
+}
+
+/// Testing enum with per-variant projected types.
+#[allow(clippy::exhaustive_enums)] // for the type itself
+#[::pin_project::pin_project(project_variants)]
+#[derive(Debug)]
+pub enum ProjectVariantsEnum<T, U> {
+    /// Struct variant.
+    Struct {
+        /// Pinned field.
+        #[pin]
+        pinned: T,
+        /// Unpinned field.
+        unpinned: U,
+    },
+    /// Tuple variant.
+    Tuple(#[pin] T, U),
+    /// Unit variant.
+    Unit,
+}
+
+/// Testing pinned drop enum with `into_parts`.
+#[allow(clippy::exhaustive_enums)] // for the type itself
+#[::pin_project::pin_project(PinnedDrop, into_parts = IntoPartsEnumParts)]
+#[derive(Debug)]
+pub enum IntoPartsEnum<T, U> {
+    /// Struct variant.
+    Struct {
+        /// Pinned field.
+        #[pin]
+        pinned: T,
+        /// Unpinned field.
+        unpinned: U,
+    },
+    /// Tuple variant.
+    Tuple(#[pin] T, U),
+    /// Unit variant.
+    Unit,
+}
+
+#[::pin_project::pinned_drop]
+impl<T, U> PinnedDrop for IntoPartsEnum<T, U> {
+    #[allow(clippy::absolute_paths)]
+    fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
+}
@@ -17,3 +17,18 @@ unsafe impl<T: ::pin_project::__private::Unpin, U: ::pin_project::__private::Unp
     ::pin_project::UnsafeUnpin for UnsafeUnpinEnum<T, U>
 {
 }
+
+/// Testing struct with a field excluded from the `Unpin` impl.
+#[allow(clippy::exhaustive_structs)] // for the type itself
+#[::pin_project::pin_project(project_replace)]
+#[derive(Debug)]
+pub struct ExcludeFromUnpinStruct<T, U> {
+    /// Pinned field excluded from the `Unpin` impl.
+    #[pin(unsafe_exclude_from_unpin)]
+    pub excluded: T,
+    /// Pinned field.
+    #[pin]
+    pub pinned: T,
+    /// Unpinned field.
+    pub unpinned: U,
+}
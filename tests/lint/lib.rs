@@ -200,6 +200,23 @@ pub mod basic {
                     fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
                 }
 
+                /// Testing pinned drop struct with replace.
+                #[allow(clippy::exhaustive_structs)] // for the type itself
+                #[::pin_project::pin_project(PinnedDrop, project_replace)]
+                #[derive(Debug)]
+                pub struct PinnedDropReplaceStruct<T, U> {
+                    /// Pinned field.
+                    #[pin]
+                    pub pinned: T,
+                    /// Unpinned field.
+                    pub unpinned: U,
+                }
+
+                #[::pin_project::pinned_drop]
+                impl<T, U> PinnedDrop for PinnedDropReplaceStruct<T, U> {
+                    fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
+                }
+
                 /// Testing default struct with replace.
                 #[::pin_project::pin_project(project_replace)]
                 #[derive(Debug)]
@@ -350,6 +367,18 @@ pub mod basic {
                     Unit,
                 }
 
+                /// Testing struct with accessors.
+                #[allow(clippy::exhaustive_structs)] // for the type itself
+                #[::pin_project::pin_project(accessors)]
+                #[derive(Debug)]
+                pub struct AccessorsStruct<T, U> {
+                    /// Pinned field.
+                    #[pin]
+                    pub pinned: T,
+                    /// Unpinned field.
+                    pub unpinned: U,
+                }
+
                 #[allow(clippy::undocumented_unsafe_blocks)]
                 unsafe impl<T: ::pin_project::__private::Unpin, U: ::pin_project::__private::Unpin>
                     ::pin_project::UnsafeUnpin for UnsafeUnpinStruct<T, U>
@@ -504,6 +533,23 @@ pub mod forbid_unsafe {
                     fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
                 }
 
+                /// Testing pinned drop struct with replace.
+                #[allow(clippy::exhaustive_structs)] // for the type itself
+                #[::pin_project::pin_project(PinnedDrop, project_replace)]
+                #[derive(Debug)]
+                pub struct PinnedDropReplaceStruct<T, U> {
+                    /// Pinned field.
+                    #[pin]
+                    pub pinned: T,
+                    /// Unpinned field.
+                    pub unpinned: U,
+                }
+
+                #[::pin_project::pinned_drop]
+                impl<T, U> PinnedDrop for PinnedDropReplaceStruct<T, U> {
+                    fn drop(self: ::pin_project::__private::Pin<&mut Self>) {}
+                }
+
                 /// Testing default struct with replace.
                 #[::pin_project::pin_project(project_replace)]
                 #[allow(clippy::exhaustive_structs)] // for the type itself
@@ -652,6 +698,18 @@ pub mod forbid_unsafe {
                     /// Unit variant.
                     Unit,
                 }
+
+                /// Testing struct with accessors.
+                #[allow(clippy::exhaustive_structs)] // for the type itself
+                #[::pin_project::pin_project(accessors)]
+                #[derive(Debug)]
+                pub struct AccessorsStruct<T, U> {
+                    /// Pinned field.
+                    #[pin]
+                    pub pinned: T,
+                    /// Unpinned field.
+                    pub unpinned: U,
+                }
             };
         }
 
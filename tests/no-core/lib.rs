@@ -31,11 +31,11 @@ mod pin_project {}
 use ::pin_project as pin;
 
 pub mod module {
-    use _core::{fmt::Debug, include, prelude::v1::derive};
+    use _core::{clone::Clone, fmt::Debug, include, marker::Copy, prelude::v1::derive};
 
     include!("../include/basic.rs");
 }
 
-use _core::{fmt::Debug, include, prelude::v1::derive};
+use _core::{clone::Clone, fmt::Debug, include, marker::Copy, prelude::v1::derive};
 
 include!("../include/basic.rs");
@@ -12,12 +12,13 @@
 mod auxiliary;
 
 use std::{
+    cell::RefCell,
     marker::{PhantomData, PhantomPinned},
     panic,
     pin::Pin,
 };
 
-use pin_project::{UnsafeUnpin, pin_project, pinned_drop};
+use pin_project::{PinProject, UnsafeUnpin, pin_project, pinned_drop};
 
 #[test]
 fn projection() {
@@ -158,6 +159,118 @@ fn enum_project_set() {
     assert_eq!(e, Enum::V2(true));
 }
 
+#[test]
+fn enum_discriminant() {
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef, project_replace = EnumProjOwn)]
+    #[repr(u8)]
+    enum Enum<T> {
+        V1(#[pin] T) = 2,
+        V2 { field: u8 } = 4,
+        V3 = 8,
+    }
+
+    #[pin_project(project = ReprCProj)]
+    #[repr(C, u8)]
+    enum ReprC<T> {
+        V1 {
+            #[pin]
+            field: T,
+        } = 1,
+        V2(u16) = 3,
+    }
+
+    fn discriminant<T>(v: &T) -> u8 {
+        // SAFETY: `T` is a `#[repr(u8)]` or `#[repr(C, u8)]` enum.
+        unsafe { *(v as *const T).cast::<u8>() }
+    }
+
+    let mut x = Enum::V1(1_u32);
+    assert_eq!(discriminant(&x), 2);
+    let mut x = Pin::new(&mut x);
+    match x.as_mut().project() {
+        EnumProj::V1(f) => assert_eq!(*f, 1),
+        _ => unreachable!(),
+    }
+    match x.as_mut().project_replace(Enum::V2 { field: 5 }) {
+        EnumProjOwn::V1(PhantomData) => {}
+        _ => unreachable!(),
+    }
+    assert_eq!(discriminant(&*x), 4);
+    match x.as_ref().project_ref() {
+        EnumProjRef::V2 { field } => assert_eq!(*field, 5),
+        _ => unreachable!(),
+    }
+    x.set(Enum::V3);
+    assert_eq!(discriminant(&*x), 8);
+
+    let mut x = ReprC::V1 { field: 1_u64 };
+    assert_eq!(discriminant(&x), 1);
+    match Pin::new(&mut x).project() {
+        ReprCProj::V1 { field } => assert_eq!(*field, 1),
+        ReprCProj::V2(_) => unreachable!(),
+    }
+    assert_eq!(discriminant(&ReprC::<u64>::V2(0)), 3);
+}
+
+#[test]
+fn zero_fields() {
+    #[pin_project(project = StructProj, project_ref = StructProjRef, project_replace)]
+    struct Struct {}
+
+    #[pin_project(project = TupleStructProj, project_ref = TupleStructProjRef, project_replace)]
+    struct TupleStruct();
+
+    #[pin_project(project = UnitStructProj, project_ref = UnitStructProjRef, project_replace)]
+    struct UnitStruct;
+
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef, project_replace = EnumProjOwn)]
+    #[derive(Debug, PartialEq)]
+    enum Enum {
+        Unit,
+        Tuple(),
+        Struct {},
+    }
+
+    #[pin_project(PinnedDrop)]
+    struct Generic<const N: usize>;
+
+    #[pinned_drop]
+    impl<const N: usize> PinnedDrop for Generic<N> {
+        fn drop(self: Pin<&mut Self>) {}
+    }
+
+    assert_unpin!(Struct);
+    assert_unpin!(TupleStruct);
+    assert_unpin!(UnitStruct);
+    assert_unpin!(Enum);
+    assert_unpin!(Generic<1>);
+
+    let mut x = Struct {};
+    let StructProj {} = Pin::new(&mut x).project();
+    let StructProjRef {} = Pin::new(&x).project_ref();
+    let _ = Pin::new(&mut x).project_replace(Struct {});
+
+    let mut x = TupleStruct();
+    let TupleStructProj() = Pin::new(&mut x).project();
+    let TupleStructProjRef() = Pin::new(&x).project_ref();
+    let _ = Pin::new(&mut x).project_replace(TupleStruct());
+
+    let mut x = UnitStruct;
+    let UnitStructProj = Pin::new(&mut x).project();
+    let UnitStructProjRef = Pin::new(&x).project_ref();
+    let _ = Pin::new(&mut x).project_replace(UnitStruct);
+
+    let mut x = Enum::Unit;
+    assert!(matches!(Pin::new(&mut x).project(), EnumProj::Unit));
+    assert!(matches!(Pin::new(&x).project_ref(), EnumProjRef::Unit));
+    assert!(matches!(Pin::new(&mut x).project_replace(Enum::Tuple()), EnumProjOwn::Unit));
+    assert!(matches!(Pin::new(&mut x).project_replace(Enum::Struct {}), EnumProjOwn::Tuple()));
+    assert_eq!(x, Enum::Struct {});
+
+    let mut x = Generic::<1>;
+    let _ = Pin::new(&mut x).project();
+}
+
 #[test]
 fn where_clause() {
     #[pin_project]
@@ -530,6 +643,42 @@ fn visibility() {
     let _: &mut u8 = y.f;
 }
 
+mod project_vis {
+    use pin_project::pin_project;
+
+    #[pin_project(PinProject, project = SProj, project_ref = SProjRef, project_vis = pub)]
+    pub struct S<T> {
+        #[pin]
+        pub f1: T,
+        pub f2: u8,
+    }
+
+    #[pin_project(PinProject, project = EProj, project_vis = pub(crate))]
+    pub(crate) enum E<T> {
+        V(#[pin] T),
+    }
+}
+
+#[test]
+fn project_vis() {
+    fn project<T: PinProject + ?Sized>(x: Pin<&mut T>) -> T::Proj<'_> {
+        x.project()
+    }
+
+    let mut x = project_vis::S { f1: 0_u8, f2: 0 };
+    let mut x = Pin::new(&mut x);
+    let project_vis::SProjRef { f1, f2 } = x.as_ref().project_ref();
+    let _: Pin<&u8> = f1;
+    let _: &u8 = f2;
+    let project_vis::SProj { f1, f2 } = project(x.as_mut());
+    let _: Pin<&mut u8> = f1;
+    let _: &mut u8 = f2;
+
+    let mut x = project_vis::E::V(0_u8);
+    let project_vis::EProj::V(f) = project(Pin::new(&mut x));
+    let _: Pin<&mut u8> = f;
+}
+
 #[test]
 fn trivial_bounds() {
     #[pin_project(project_replace)]
@@ -893,3 +1042,929 @@ fn project_replace_panic() {
     assert!(c);
     assert!(d);
 }
+
+#[test]
+fn accessors() {
+    #[pin_project(accessors)]
+    struct Struct<T, U> {
+        #[pin]
+        f1: T,
+        f2: U,
+    }
+
+    let mut s = Struct { f1: 1, f2: 2 };
+    let mut s = Pin::new(&mut s);
+
+    let _: Pin<&mut i32> = s.as_mut().f1();
+    assert_eq!(*s.as_mut().f1(), 1);
+    let _: &mut i32 = s.as_mut().f2();
+    *s.as_mut().f2() = 3;
+    let _: Pin<&i32> = s.as_ref().f1_ref();
+    let _: &i32 = s.as_ref().f2_ref();
+    assert_eq!(*s.as_ref().f2_ref(), 3);
+
+    s.as_mut().set_f1(4);
+    assert_eq!(s.f1, 4);
+
+    #[pin_project(accessors)]
+    struct Dst<T: ?Sized> {
+        #[pin]
+        f1: PhantomPinned,
+        #[pin]
+        f2: T,
+    }
+
+    let mut x = Box::pin(Dst { f1: PhantomPinned, f2: 0_u8 });
+    x.as_mut().set_f1(PhantomPinned);
+    x.as_mut().set_f2(1);
+    let x: Pin<&mut Dst<dyn core::fmt::Debug + Unpin>> = x.as_mut();
+    let _: Pin<&mut (dyn core::fmt::Debug + Unpin)> = x.f2();
+
+    #[pin_project(accessors)]
+    struct DynType {
+        #[pin]
+        f: dyn core::fmt::Debug,
+    }
+
+    #[pin_project(accessors)]
+    struct RawIdent {
+        #[pin]
+        r#type: i32,
+    }
+
+    let mut x = RawIdent { r#type: 1 };
+    Pin::new(&mut x).set_type(2);
+    assert_eq!(*Pin::new(&mut x).r#type(), 2);
+    assert_eq!(*Pin::new(&x).type_ref(), 2);
+}
+
+#[test]
+fn pin_project_trait() {
+    fn project<T: PinProject + ?Sized>(x: Pin<&mut T>) -> T::Proj<'_> {
+        x.project()
+    }
+    fn project_ref<T: PinProject + ?Sized>(x: Pin<&T>) -> T::ProjRef<'_> {
+        x.project_ref()
+    }
+
+    #[pin_project(PinProject)]
+    struct Struct<T, U> {
+        #[pin]
+        f1: T,
+        f2: U,
+    }
+
+    let mut s = Struct { f1: 1, f2: 2 };
+    let mut s = Pin::new(&mut s);
+    let this = project(s.as_mut());
+    let _: Pin<&mut i32> = this.f1;
+    let _: &mut i32 = this.f2;
+    let this = project_ref(s.as_ref());
+    let _: Pin<&i32> = this.f1;
+    let _: &i32 = this.f2;
+
+    #[pin_project(PinProject)]
+    struct TupleStruct<T, U>(#[pin] T, U);
+
+    let mut s = TupleStruct(1, 2);
+    let this = project(Pin::new(&mut s));
+    let _: Pin<&mut i32> = this.0;
+    let _: &mut i32 = this.1;
+
+    #[pin_project(PinProject, project = EnumProj, project_ref = EnumProjRef)]
+    enum Enum<T, U> {
+        V {
+            #[pin]
+            f1: T,
+            f2: U,
+        },
+        None,
+    }
+
+    let mut e = Enum::V { f1: 1, f2: 2 };
+    let this: EnumProj<'_, i32, i32> = project(Pin::new(&mut e));
+    if let EnumProj::V { f1, f2 } = this {
+        let _: Pin<&mut i32> = f1;
+        let _: &mut i32 = f2;
+    } else {
+        unreachable!();
+    }
+    let _: EnumProjRef<'_, i32, i32> = project_ref(Pin::new(&e));
+
+    #[pin_project(PinProject)]
+    enum UnnamedEnum<T> {
+        V(#[pin] T),
+    }
+
+    let mut e = UnnamedEnum::V(1);
+    let _ = project(Pin::new(&mut e));
+    let _ = project_ref(Pin::new(&e));
+
+    #[pin_project(PinProject)]
+    pub struct PubStruct<T: ?Sized> {
+        #[pin]
+        f: T,
+    }
+
+    let mut s = PubStruct { f: 1 };
+    let s: Pin<&mut PubStruct<dyn core::fmt::Debug + Unpin>> = Pin::new(&mut s);
+    let _: Pin<&mut (dyn core::fmt::Debug + Unpin)> = project(s).f;
+
+    // The named projected types of a `pub` type are `pub(crate)` by default.
+    #[pin_project(PinProject, project = PubNamedProj, project_vis = pub)]
+    pub struct PubNamed<T> {
+        #[pin]
+        f: T,
+    }
+
+    let mut s = PubNamed { f: 1 };
+    let PubNamedProj { f } = project(Pin::new(&mut s));
+    let _: Pin<&mut i32> = f;
+
+    // `PinProject` is only implemented with `PinProject` argument.
+    #[pin_project]
+    pub struct NoArg<T> {
+        #[pin]
+        f: T,
+    }
+
+    #[pin_project(project = NoArgNamedProj)]
+    struct NoArgNamed<T> {
+        #[pin]
+        f: T,
+    }
+
+    static_assertions::assert_not_impl_all!(NoArg<()>: PinProject);
+    static_assertions::assert_not_impl_all!(NoArgNamed<()>: PinProject);
+}
+
+#[test]
+fn project_field() {
+    #[pin_project(PinProject, project = InnerProj, project_ref = InnerProjRef, project_replace)]
+    struct Inner<T> {
+        #[pin]
+        pinned: T,
+        unpinned: usize,
+    }
+
+    #[pin_project(project_replace, accessors)]
+    struct Struct<T> {
+        #[pin(project)]
+        inner: Inner<T>,
+        unpinned: usize,
+    }
+
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef)]
+    enum Enum<T: PinProject> {
+        V(#[pin(project)] T),
+        None,
+    }
+
+    let mut x = Struct { inner: Inner { pinned: PhantomPinned, unpinned: 1 }, unpinned: 2 };
+    let mut x = unsafe { Pin::new_unchecked(&mut x) };
+
+    let this = x.as_mut().project();
+    let InnerProj { pinned, unpinned } = this.inner;
+    let _: Pin<&mut PhantomPinned> = pinned;
+    *unpinned += 1;
+    *this.unpinned += 1;
+
+    let this = x.as_ref().project_ref();
+    let InnerProjRef { pinned, unpinned } = this.inner;
+    let _: Pin<&PhantomPinned> = pinned;
+    assert_eq!(*unpinned, 2);
+    assert_eq!(*this.unpinned, 3);
+
+    *x.as_mut().inner().unpinned += 1;
+    assert_eq!(*x.as_ref().inner_ref().unpinned, 3);
+    x.as_mut().set_inner(Inner { pinned: PhantomPinned, unpinned: 4 });
+    assert_eq!(*x.as_ref().inner_ref().unpinned, 4);
+
+    let old = x.as_mut().project_replace(Struct {
+        inner: Inner { pinned: PhantomPinned, unpinned: 5 },
+        unpinned: 6,
+    });
+    let _: PhantomData<Inner<PhantomPinned>> = old.inner;
+    assert_eq!(old.unpinned, 3);
+
+    assert_not_unpin!(Struct<PhantomPinned>);
+    assert_unpin!(Struct<()>);
+
+    let mut e = Enum::V(Inner { pinned: 1, unpinned: 2 });
+    match Pin::new(&mut e).project() {
+        EnumProj::V(InnerProj { pinned, unpinned }) => {
+            let _: Pin<&mut i32> = pinned;
+            *unpinned += 1;
+        }
+        EnumProj::None => unreachable!(),
+    }
+    match Pin::new(&e).project_ref() {
+        EnumProjRef::V(InnerProjRef { unpinned, .. }) => assert_eq!(*unpinned, 3),
+        EnumProjRef::None => unreachable!(),
+    }
+}
+
+#[test]
+fn elements() {
+    #[pin_project(project_replace, accessors)]
+    struct Struct<T, const N: usize> {
+        #[pin(elements)]
+        array: [T; N],
+        unpinned: usize,
+    }
+
+    #[pin_project]
+    struct Slice<T> {
+        unpinned: usize,
+        #[pin(elements)]
+        slice: [T],
+    }
+
+    impl<T> Slice<T> {
+        fn last(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+            self.project().slice.into_iter().next_back()
+        }
+    }
+
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef)]
+    enum Enum<T> {
+        V(#[pin(elements)] [T; 2]),
+        None,
+    }
+
+    let mut x = Struct { array: [PhantomPinned, PhantomPinned], unpinned: 1 };
+    let mut x = unsafe { Pin::new_unchecked(&mut x) };
+
+    let mut this = x.as_mut().project();
+    assert_eq!(this.array.len(), 2);
+    assert!(!this.array.is_empty());
+    for elem in &mut this.array {
+        let _: Pin<&mut PhantomPinned> = elem;
+    }
+    let _: Pin<&mut PhantomPinned> = this.array.get_mut(1).unwrap();
+    assert!(this.array.get_mut(2).is_none());
+    *this.unpinned += 1;
+
+    let this = x.as_ref().project_ref();
+    assert_eq!(this.array.iter().count(), 2);
+    let _: Pin<&PhantomPinned> = this.array.get(0).unwrap();
+    assert_eq!(*this.unpinned, 2);
+
+    assert_eq!(x.as_mut().array().into_iter().len(), 2);
+    assert_eq!(x.as_ref().array_ref().len(), 2);
+
+    let old = x.as_mut().project_replace(Struct { array: [PhantomPinned; 2], unpinned: 3 });
+    let _: PhantomData<[PhantomPinned; 2]> = old.array;
+    assert_eq!(old.unpinned, 2);
+
+    assert_not_unpin!(Struct<PhantomPinned, 2>);
+    assert_unpin!(Struct<(), 2>);
+    assert_not_unpin!(Slice<PhantomPinned>);
+    assert_unpin!(Slice<()>);
+
+    let mut x = Struct { array: [1, 2, 3], unpinned: 0 };
+    for mut elem in Pin::new(&mut x).project().array {
+        *elem += 1;
+        elem.set(*elem * 10);
+    }
+    assert_eq!(x.array, [20, 30, 40]);
+
+    let mut e = Enum::V([1, 2]);
+    match Pin::new(&mut e).project() {
+        EnumProj::V(mut array) => array.get_mut(0).unwrap().set(3),
+        EnumProj::None => unreachable!(),
+    }
+    match Pin::new(&e).project_ref() {
+        EnumProjRef::V(array) => assert_eq!(*array.get(0).unwrap(), 3),
+        EnumProjRef::None => unreachable!(),
+    }
+}
+
+#[test]
+fn skip_field() {
+    use std::cell::Cell;
+
+    struct D<'a>(&'a Cell<usize>);
+
+    impl Drop for D<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[pin_project(project = StructProj, project_ref = StructProjRef, project_replace, accessors)]
+    struct Struct<'a, T, M> {
+        #[pin]
+        #[pin(skip)]
+        pinned: T,
+        #[pin(skip)]
+        unpinned: D<'a>,
+        #[pin(skip)]
+        marker: PhantomData<M>,
+        field: usize,
+    }
+
+    #[pin_project(project_replace)]
+    struct TupleStruct<T>(usize, #[pin(skip)] PhantomData<T>, #[pin] usize);
+
+    #[pin_project(project = EnumProj, project_ref = EnumProjRef, project_replace = EnumProjOwn)]
+    enum Enum<'a, T> {
+        V {
+            #[pin]
+            #[pin(skip)]
+            pinned: T,
+            #[pin(skip)]
+            unpinned: D<'a>,
+            field: usize,
+        },
+        Skipped(#[pin(skip)] PhantomData<T>),
+    }
+
+    #[pin_project]
+    struct Unsized<T: ?Sized> {
+        field: u8,
+        #[pin]
+        #[pin(skip)]
+        skipped: T,
+    }
+
+    assert_unpin!(Struct<'_, (), PhantomPinned>);
+    assert_not_unpin!(Struct<'_, PhantomPinned, ()>);
+    assert_unpin!(TupleStruct<PhantomPinned>);
+    assert_unpin!(Enum<'_, ()>);
+    assert_not_unpin!(Enum<'_, PhantomPinned>);
+
+    let dropped = Cell::new(0);
+    let mut x = Box::pin(Struct {
+        pinned: PhantomPinned,
+        unpinned: D(&dropped),
+        marker: PhantomData::<()>,
+        field: 1,
+    });
+    let StructProj { field, .. } = x.as_mut().project();
+    *field += 1;
+    let StructProjRef { field, .. } = x.as_ref().project_ref();
+    assert_eq!(*field, 2);
+    *x.as_mut().field() += 1;
+    assert_eq!(*x.as_ref().field_ref(), 3);
+
+    let replacement =
+        Struct { pinned: PhantomPinned, unpinned: D(&dropped), marker: PhantomData, field: 4 };
+    let old = x.as_mut().project_replace(replacement);
+    assert_eq!(dropped.get(), 1);
+    assert_eq!(old.field, 3);
+    drop(x);
+    assert_eq!(dropped.get(), 2);
+
+    // The fields after a skipped field keep their indices.
+    let mut x = TupleStruct(1, PhantomData::<PhantomPinned>, 2);
+    let proj = Pin::new(&mut x).project();
+    *proj.0 += 1;
+    *proj.2.get_mut() += 1;
+    let proj_ref = Pin::new(&x).project_ref();
+    assert_eq!(*proj_ref.0, 2);
+    assert_eq!(*proj_ref.2, 3);
+    let proj_own = Pin::new(&mut x).project_replace(TupleStruct(4, PhantomData, 5));
+    assert_eq!(proj_own.0, 2);
+    let _: PhantomData<usize> = proj_own.2;
+    assert_eq!((x.0, x.2), (4, 5));
+
+    let dropped = Cell::new(0);
+    let mut x = Box::pin(Enum::V { pinned: 1, unpinned: D(&dropped), field: 2 });
+    match x.as_mut().project() {
+        EnumProj::V { field, .. } => *field += 1,
+        EnumProj::Skipped(..) => unreachable!(),
+    }
+    match x.as_ref().project_ref() {
+        EnumProjRef::V { field, .. } => assert_eq!(*field, 3),
+        EnumProjRef::Skipped(..) => unreachable!(),
+    }
+    match x.as_mut().project_replace(Enum::Skipped(PhantomData)) {
+        EnumProjOwn::V { field, .. } => assert_eq!(field, 3),
+        EnumProjOwn::Skipped(..) => unreachable!(),
+    }
+    assert_eq!(dropped.get(), 1);
+
+    let mut x = Unsized { field: 1, skipped: [0_u8; 4] };
+    let x: Pin<&mut Unsized<[u8]>> = Pin::new(&mut x);
+    *x.project().field += 1;
+}
+
+#[test]
+fn proj_attrs() {
+    #[pin_project(
+        project = StructProj,
+        project_ref = StructProjRef,
+        project_replace = StructProjOwn,
+        project_attr(derive(Debug), must_use),
+        project_ref_attr(derive(Debug)),
+        project_replace_attr(derive(Debug, PartialEq))
+    )]
+    struct Struct<T, U> {
+        #[pin]
+        pinned: T,
+        #[pin(project_attr(allow(dead_code)))]
+        unpinned: U,
+    }
+
+    let mut x = Struct { pinned: 1, unpinned: 2 };
+    let mut x = Pin::new(&mut x);
+    assert_eq!(format!("{:?}", x.as_mut().project()), "StructProj { pinned: 1, unpinned: 2 }");
+    assert_eq!(
+        format!("{:?}", x.as_ref().project_ref()),
+        "StructProjRef { pinned: 1, unpinned: 2 }"
+    );
+    assert_eq!(x.project_replace(Struct { pinned: 3, unpinned: 4 }), StructProjOwn {
+        pinned: PhantomData,
+        unpinned: 2
+    });
+
+    #[pin_project(
+        project = EnumProj,
+        project_replace = EnumProjOwn,
+        project_attr(derive(Debug)),
+        project_replace_attr(derive(Debug, Default, PartialEq))
+    )]
+    enum Enum<T, U> {
+        V1(#[pin] T, U),
+        #[pin(project_attr(allow(dead_code)), project_replace_attr(default))]
+        V2,
+    }
+
+    let mut x = Enum::V1(1, 2);
+    let mut x = Pin::new(&mut x);
+    assert_eq!(format!("{:?}", x.as_mut().project()), "V1(1, 2)");
+    assert_eq!(x.as_mut().project_replace(Enum::V2), EnumProjOwn::V1(PhantomData, 2));
+    assert_eq!(x.project_replace(Enum::V2), EnumProjOwn::<i32, i32>::default());
+}
+
+#[test]
+fn project_fn() {
+    #[pin_project(project_fn = pin_mut_fields, project_ref_fn = pin_ref_fields)]
+    struct Struct<T, U> {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    }
+
+    // The default names are available for user-defined methods.
+    impl<T, U> Struct<T, U> {
+        fn project(&self) -> &U {
+            &self.unpinned
+        }
+    }
+
+    let mut x = Struct { pinned: 1, unpinned: 2 };
+    assert_eq!(*x.project(), 2);
+    let mut x = Pin::new(&mut x);
+    let _: Pin<&i32> = x.as_ref().pin_ref_fields().pinned;
+    let this = x.as_mut().pin_mut_fields();
+    let _: Pin<&mut i32> = this.pinned;
+    *this.unpinned = 3;
+    assert_eq!(x.unpinned, 3);
+
+    #[pin_project(
+        project = EnumProj,
+        project_replace = EnumProjOwn,
+        project_fn = pin_mut_fields,
+        project_replace_fn = take_fields
+    )]
+    enum Enum<T> {
+        V(#[pin] T),
+        None,
+    }
+
+    let mut x = Enum::V(1);
+    let mut x = Pin::new(&mut x);
+    match x.as_mut().pin_mut_fields() {
+        EnumProj::V(pinned) => {
+            let _: Pin<&mut i32> = pinned;
+        }
+        EnumProj::None => unreachable!(),
+    }
+    assert!(matches!(x.take_fields(Enum::None), EnumProjOwn::V(PhantomData)));
+
+    #[pin_project(!project_ref)]
+    struct NoProjRef<T> {
+        #[pin]
+        pinned: T,
+    }
+
+    static_assertions::assert_not_impl_all!(NoProjRef<()>: PinProject);
+    let mut x = NoProjRef { pinned: 1 };
+    let _: Pin<&mut i32> = Pin::new(&mut x).project().pinned;
+}
+
+#[test]
+fn inferred_names() {
+    #[pin_project(project = _, project_ref = _, project_replace = _)]
+    enum Enum<T, U> {
+        V1(#[pin] T, U),
+        V2,
+    }
+
+    let mut x = Enum::V1(1, 2);
+    let mut x = Pin::new(&mut x);
+    match x.as_mut().project() {
+        EnumProj::V1(pinned, unpinned) => {
+            let _: Pin<&mut i32> = pinned;
+            let _: &mut i32 = unpinned;
+        }
+        EnumProj::V2 => unreachable!(),
+    }
+    match x.as_ref().project_ref() {
+        EnumProjRef::V1(pinned, unpinned) => {
+            let _: Pin<&i32> = pinned;
+            let _: &i32 = unpinned;
+        }
+        EnumProjRef::V2 => unreachable!(),
+    }
+    assert!(matches!(x.project_replace(Enum::V2), EnumProjOwn::V1(PhantomData, 2)));
+
+    #[pin_project(project = _)]
+    struct Struct<T> {
+        #[pin]
+        pinned: T,
+    }
+
+    let mut x = Struct { pinned: 1 };
+    let StructProj { pinned } = Pin::new(&mut x).project();
+    let _: Pin<&mut i32> = pinned;
+}
+
+#[test]
+fn project_variants() {
+    #[pin_project(project_variants)]
+    enum Enum<T, U> {
+        Struct {
+            #[pin]
+            pinned: T,
+            unpinned: U,
+        },
+        Tuple(#[pin] T, U),
+        HTTPUnit,
+    }
+
+    fn take_struct<T, U>(this: EnumStructProj<'_, T, U>) -> Pin<&mut T> {
+        this.pinned
+    }
+
+    let mut x = Enum::Struct { pinned: 1, unpinned: 2 };
+    let mut x = Pin::new(&mut x);
+    assert!(x.is_struct());
+    assert!(!x.is_tuple());
+    assert!(!x.is_http_unit());
+    assert!(x.as_mut().project_tuple().is_none());
+    assert!(x.as_ref().project_tuple_ref().is_none());
+    let EnumStructProjRef { pinned, unpinned, .. } = x.as_ref().project_struct_ref().unwrap();
+    let _: Pin<&i32> = pinned;
+    assert_eq!(*unpinned, 2);
+    let this = x.as_mut().project_struct().unwrap();
+    *this.unpinned = 3;
+    let _: Pin<&mut i32> = take_struct(this);
+    assert!(matches!(*x, Enum::Struct { pinned: 1, unpinned: 3 }));
+
+    let mut x = Enum::<i32, i32>::Tuple(1, 2);
+    let mut x = Pin::new(&mut x);
+    assert!(x.is_tuple());
+    let EnumTupleProj(pinned, unpinned, _) = x.as_mut().project_tuple().unwrap();
+    let _: Pin<&mut i32> = pinned;
+    let _: &mut i32 = unpinned;
+
+    let mut x = Enum::<i32, i32>::HTTPUnit;
+    let x = Pin::new(&mut x);
+    assert!(x.is_http_unit());
+    let EnumHTTPUnitProj(_) = x.project_http_unit().unwrap();
+}
+
+#[test]
+fn project_transition() {
+    #[pin_project(project_replace = StructProjOwn, project_transition)]
+    struct Struct<T, U> {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    }
+
+    let mut x = Struct { pinned: 1, unpinned: 2 };
+    let x = Pin::new(&mut x);
+    x.project_transition(|StructProjOwn { pinned, unpinned }| {
+        let _: PhantomData<i32> = pinned;
+        Struct { pinned: unpinned, unpinned: 3 }
+    });
+
+    #[pin_project(project_replace = MapProjOwn, project_transition)]
+    enum Map<Fut, F, T> {
+        Incomplete {
+            #[pin]
+            future: Fut,
+            f: F,
+        },
+        Complete(T),
+    }
+
+    let mut x = Map::Incomplete { future: 1, f: |n: i32| n + 1 };
+    let mut x = Pin::new(&mut x);
+    x.as_mut().project_transition(|this| match this {
+        MapProjOwn::Incomplete { f, .. } => Map::Complete(f(1)),
+        MapProjOwn::Complete(_) => unreachable!(),
+    });
+    assert!(matches!(*x, Map::Complete(2)));
+
+    // The pinned fields are dropped before the closure is called.
+    struct D<'a>(&'a RefCell<Vec<&'static str>>, &'static str);
+    impl Drop for D<'_> {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push(self.1);
+        }
+    }
+
+    let log = RefCell::new(vec![]);
+    let mut x = Struct { pinned: D(&log, "pinned"), unpinned: D(&log, "unpinned") };
+    Pin::new(&mut x).project_transition(|this| {
+        log.borrow_mut().push("closure");
+        drop(this.unpinned);
+        Struct { pinned: D(&log, "new pinned"), unpinned: D(&log, "new unpinned") }
+    });
+    assert_eq!(*log.borrow(), ["pinned", "closure", "unpinned"]);
+    drop(x);
+    assert_eq!(*log.borrow(), ["pinned", "closure", "unpinned", "new pinned", "new unpinned"]);
+}
+
+#[test]
+fn project_take() {
+    #[pin_project(project_replace = StructProjOwn, project_take)]
+    #[derive(Default)]
+    struct Struct<T, U> {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    }
+
+    let mut x = Struct { pinned: 1, unpinned: 2 };
+    let mut x = Pin::new(&mut x);
+    let StructProjOwn { pinned, unpinned } = x.as_mut().project_take();
+    let _: PhantomData<i32> = pinned;
+    assert_eq!(unpinned, 2);
+    assert_eq!(x.pinned, 0);
+    assert_eq!(x.unpinned, 0);
+
+    #[pin_project(project_replace = StateProjOwn, project_take)]
+    #[derive(Default)]
+    enum State<F, T> {
+        Pending {
+            #[pin]
+            future: F,
+            value: T,
+        },
+        #[default]
+        Done,
+    }
+
+    let mut x = State::Pending { future: (), value: "hello" };
+    let mut x = Pin::new(&mut x);
+    match x.as_mut().project_take() {
+        StateProjOwn::Pending { value, .. } => assert_eq!(value, "hello"),
+        StateProjOwn::Done => unreachable!(),
+    }
+    assert!(matches!(*x, State::Done));
+
+    // `project_take` is not available, but this still compiles.
+    #[pin_project(project_replace)]
+    struct NotDefault(#[pin] PhantomPinned);
+}
+
+#[test]
+fn into_parts() {
+    #[pin_project(PinnedDrop, into_parts = StructParts)]
+    struct Struct<'a, T, U> {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+        dropped: &'a mut bool,
+    }
+
+    #[pinned_drop]
+    impl<T, U> PinnedDrop for Struct<'_, T, U> {
+        fn drop(self: Pin<&mut Self>) {
+            **self.project().dropped = true;
+        }
+    }
+
+    let mut dropped = false;
+    let x = Struct { pinned: 1, unpinned: "a", dropped: &mut dropped };
+    let StructParts { pinned, unpinned, dropped: d } = x.into_parts();
+    assert_eq!(pinned, 1);
+    assert_eq!(unpinned, "a");
+    assert!(!*d);
+    assert!(!dropped);
+
+    #[pin_project(PinnedDrop, into_parts = _)]
+    struct TupleStruct<T>(#[pin] T, Vec<T>);
+
+    #[pinned_drop]
+    impl<T> PinnedDrop for TupleStruct<T> {
+        fn drop(self: Pin<&mut Self>) {
+            unreachable!();
+        }
+    }
+
+    let TupleStructParts(pinned, unpinned) = TupleStruct(1, vec![2]).into_parts();
+    assert_eq!(pinned, 1);
+    assert_eq!(unpinned, [2]);
+
+    #[pin_project(PinnedDrop, into_parts = EnumParts)]
+    enum Enum<T, U> {
+        Struct {
+            #[pin]
+            pinned: T,
+            unpinned: U,
+        },
+        Tuple(#[pin] T, U),
+        Unit,
+    }
+
+    #[pinned_drop]
+    impl<T, U> PinnedDrop for Enum<T, U> {
+        fn drop(self: Pin<&mut Self>) {
+            unreachable!();
+        }
+    }
+
+    match (Enum::Struct { pinned: 1, unpinned: String::from("a") }).into_parts() {
+        EnumParts::Struct { pinned, unpinned } => {
+            assert_eq!(pinned, 1);
+            assert_eq!(unpinned, "a");
+        }
+        EnumParts::Tuple(..) | EnumParts::Unit => unreachable!(),
+    }
+    assert!(matches!(Enum::<i32, i32>::Tuple(1, 2).into_parts(), EnumParts::Tuple(1, 2)));
+    assert!(matches!(Enum::<i32, i32>::Unit.into_parts(), EnumParts::Unit));
+}
+
+#[test]
+fn project_replace_with() {
+    struct D<'a>(&'a RefCell<Vec<&'static str>>, &'static str);
+    impl Drop for D<'_> {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push(self.1);
+        }
+    }
+
+    #[pin_project(project_replace, project_replace_with)]
+    struct Struct<T, U> {
+        #[pin]
+        pinned1: T,
+        #[pin]
+        pinned2: T,
+        unpinned: U,
+    }
+
+    let log = RefCell::new(vec![]);
+    let mut x = Struct { pinned1: D(&log, "1"), pinned2: D(&log, "2"), unpinned: 3 };
+    let x = Pin::new(&mut x);
+    let this = x.project_replace_with(
+        Struct { pinned1: D(&log, "new 1"), pinned2: D(&log, "new 2"), unpinned: 4 },
+        |this| {
+            let _: Pin<&mut D<'_>> = this.pinned1;
+            this.pinned2.0.borrow_mut().push(this.pinned2.1);
+            *this.unpinned += 1;
+        },
+    );
+    assert_eq!(this.unpinned, 4);
+    assert_eq!(*log.borrow(), ["2", "1", "2"]);
+
+    #[pin_project(project = EnumProj, project_replace = EnumProjOwn, project_replace_with)]
+    enum Enum<T, U> {
+        Struct {
+            #[pin]
+            pinned: T,
+            unpinned: U,
+        },
+        Unit,
+    }
+
+    let mut x = Enum::Struct { pinned: 1, unpinned: 2 };
+    let x = Pin::new(&mut x);
+    let mut pinned = 0;
+    match x.project_replace_with(Enum::Unit, |this| {
+        if let EnumProj::Struct { pinned: p, .. } = this {
+            pinned = *p;
+        }
+    }) {
+        EnumProjOwn::Struct { unpinned, .. } => assert_eq!(unpinned, 2),
+        EnumProjOwn::Unit => unreachable!(),
+    }
+    assert_eq!(pinned, 1);
+}
+
+#[test]
+fn variant_drop() {
+    use std::cell::Cell;
+
+    #[pin_project(
+        project = StateProj,
+        project_replace = StateProjOwn,
+        project_replace_with,
+        project_transition,
+        project_variants
+    )]
+    enum State<'a, F: Copy> {
+        #[pin(drop = Self::cancel)]
+        Running {
+            #[pin]
+            future: F,
+            canceled: &'a Cell<Option<F>>,
+        },
+        #[pin(drop = on_drop_waiting)]
+        Waiting(&'a Cell<bool>),
+        Done(&'a Cell<bool>),
+    }
+
+    impl<F: Copy> State<'_, F> {
+        fn cancel(this: StateRunningProj<'_, '_, F>) {
+            let future: Pin<&mut F> = this.future;
+            this.canceled.set(Some(*future));
+        }
+    }
+
+    fn on_drop_waiting<F: Copy>(this: StateWaitingProj<'_, '_, F>) {
+        let StateWaitingProj(waiting, _) = this;
+        waiting.set(true);
+    }
+
+    let canceled = Cell::new(None);
+    drop(State::Running { future: 1, canceled: &canceled });
+    assert_eq!(canceled.get(), Some(1));
+
+    let waiting = Cell::new(false);
+    drop(State::<i32>::Waiting(&waiting));
+    assert!(waiting.get());
+
+    let done = Cell::new(false);
+    drop(State::<i32>::Done(&done));
+    assert!(!done.get());
+
+    // The hook is also called when the old value is replaced.
+    let canceled = Cell::new(None);
+    let mut x = State::Running { future: 1, canceled: &canceled };
+    let mut x = Pin::new(&mut x);
+    let _ = x.as_mut().project_replace(State::Running { future: 2, canceled: &canceled });
+    assert_eq!(canceled.get(), Some(1));
+    let _ = x.as_mut().project_replace_with(State::Waiting(&waiting), |_| {});
+    assert_eq!(canceled.get(), Some(2));
+    waiting.set(false);
+    x.as_mut().project_transition(|this| match this {
+        StateProjOwn::Waiting(_) => State::Done(&done),
+        _ => unreachable!(),
+    });
+    assert!(waiting.get());
+    assert!(matches!(*x, State::Done(_)));
+    assert!(!done.get());
+}
+
+#[test]
+fn pinned_drop_fn() {
+    trait Counter {
+        fn counter(self: Pin<&mut Self>) -> &mut usize;
+    }
+
+    fn count_drop<T: Counter>(this: Pin<&mut T>) {
+        *this.counter() += 1;
+    }
+
+    #[pin_project(PinnedDrop = count_drop, project_replace)]
+    struct Struct<'a, T> {
+        #[pin]
+        pinned: T,
+        counter: &'a mut usize,
+    }
+
+    impl<T> Counter for Struct<'_, T> {
+        fn counter(self: Pin<&mut Self>) -> &mut usize {
+            self.project().counter
+        }
+    }
+
+    #[pin_project(PinnedDrop = count_drop)]
+    struct TupleStruct<'a, T>(#[pin] T, &'a mut usize);
+
+    impl<T> Counter for TupleStruct<'_, T> {
+        fn counter(self: Pin<&mut Self>) -> &mut usize {
+            self.project().1
+        }
+    }
+
+    let mut counter = 0;
+    drop(Struct { pinned: (), counter: &mut counter });
+    assert_eq!(counter, 1);
+    drop(TupleStruct((), &mut counter));
+    assert_eq!(counter, 2);
+
+    let (mut a, mut b) = (0, 0);
+    let mut x = Struct { pinned: (), counter: &mut a };
+    let this = Pin::new(&mut x).project_replace(Struct { pinned: (), counter: &mut b });
+    assert_eq!(*this.counter, 1);
+    drop(x);
+    assert_eq!(b, 1);
+}
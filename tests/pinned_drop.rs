@@ -262,6 +262,104 @@ fn inside_macro() {
     mac!(1);
 }
 
+#[test]
+fn project_replace() {
+    use std::{cell::Cell, panic};
+
+    struct D<'a>(&'a Cell<usize>);
+
+    impl Drop for D<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[pin_project(PinnedDrop, project_replace)]
+    struct Struct<'a> {
+        hook: &'a Cell<usize>,
+        panic: bool,
+        #[pin]
+        pinned: D<'a>,
+        unpinned: D<'a>,
+    }
+
+    #[pinned_drop]
+    impl PinnedDrop for Struct<'_> {
+        fn drop(self: Pin<&mut Self>) {
+            let this = self.project();
+            this.hook.set(this.hook.get() + 1);
+            assert!(!*this.panic);
+        }
+    }
+
+    #[pin_project(PinnedDrop, project = EnumProj, project_replace = EnumProjOwn)]
+    enum Enum<'a> {
+        V {
+            hook: &'a Cell<usize>,
+            #[pin]
+            pinned: D<'a>,
+            unpinned: D<'a>,
+        },
+        None,
+    }
+
+    #[pinned_drop]
+    impl PinnedDrop for Enum<'_> {
+        fn drop(self: Pin<&mut Self>) {
+            if let EnumProj::V { hook, .. } = self.project() {
+                hook.set(hook.get() + 1);
+            }
+        }
+    }
+
+    let hook = Cell::new(0);
+    let pinned = Cell::new(0);
+    let unpinned = Cell::new(0);
+    let mut x =
+        Box::pin(Struct { hook: &hook, panic: false, pinned: D(&pinned), unpinned: D(&unpinned) });
+    let replacement =
+        Struct { hook: &hook, panic: false, pinned: D(&pinned), unpinned: D(&unpinned) };
+    let old = x.as_mut().project_replace(replacement);
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 0));
+    drop(old);
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 1));
+    drop(x);
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (2, 2, 2));
+
+    // If `PinnedDrop::drop` panics, all the fields are still dropped and
+    // the replacement is still written.
+    let hook = Cell::new(0);
+    let pinned = Cell::new(0);
+    let unpinned = Cell::new(0);
+    let mut x =
+        Box::pin(Struct { hook: &hook, panic: true, pinned: D(&pinned), unpinned: D(&unpinned) });
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let replacement =
+            Struct { hook: &hook, panic: false, pinned: D(&pinned), unpinned: D(&unpinned) };
+        let _ = x.as_mut().project_replace(replacement);
+    }));
+    assert!(res.is_err());
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 1));
+    assert!(!x.panic);
+    drop(x);
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (2, 2, 2));
+
+    let hook = Cell::new(0);
+    let pinned = Cell::new(0);
+    let unpinned = Cell::new(0);
+    let mut x = Box::pin(Enum::V { hook: &hook, pinned: D(&pinned), unpinned: D(&unpinned) });
+    match x.as_mut().project_replace(Enum::None) {
+        EnumProjOwn::V { unpinned: u, .. } => {
+            assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 0));
+            drop(u);
+        }
+        EnumProjOwn::None => unreachable!(),
+    }
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 1));
+    drop(x);
+    assert_eq!((hook.get(), pinned.get(), unpinned.get()), (1, 1, 1));
+}
+
 mod self_path {
     use super::*;
 
@@ -152,3 +152,93 @@ pub mod not_unpin {
 
     assert_not_unpin!(PinRef<'_, (), ()>);
 }
+
+pub mod unpin_where {
+    use std::{marker::PhantomPinned, rc::Rc};
+
+    use pin_project::pin_project;
+
+    struct Inner<T> {
+        f: T,
+    }
+
+    struct NotClone;
+
+    #[pin_project(Unpin where U: Clone)]
+    struct Struct<T, U> {
+        #[pin]
+        f1: Inner<T>,
+        f2: U,
+    }
+
+    assert_unpin!(Struct<(), ()>);
+    assert_not_unpin!(Struct<(), NotClone>);
+    assert_not_unpin!(Struct<PhantomPinned, ()>);
+    assert_not_unpin!(Struct<PhantomPinned, NotClone>);
+
+    #[pin_project(project = EnumProj, Unpin where T: Clone, Self: Send,)]
+    enum Enum<T, U> {
+        V1 {
+            #[pin]
+            f1: Inner<T>,
+            f2: U,
+        },
+    }
+
+    assert_unpin!(Enum<(), ()>);
+    assert_not_unpin!(Enum<NotClone, ()>);
+    assert_not_unpin!(Enum<(), Rc<()>>);
+    assert_not_unpin!(Enum<PhantomPinned, ()>);
+
+    #[pin_project(Unpin where T: Clone)]
+    struct TrivialBounds<T> {
+        #[pin]
+        f1: PhantomPinned,
+        f2: T,
+    }
+
+    assert_not_unpin!(TrivialBounds<()>);
+}
+
+pub mod unsafe_exclude_from_unpin {
+    use std::marker::PhantomPinned;
+
+    use pin_project::pin_project;
+
+    struct Inner<T> {
+        f: T,
+    }
+
+    #[pin_project]
+    struct Struct<T, U, V> {
+        #[pin(unsafe_exclude_from_unpin)]
+        f1: Inner<T>,
+        #[pin]
+        f2: Inner<U>,
+        f3: V,
+    }
+
+    assert_unpin!(Struct<(), (), ()>);
+    assert_unpin!(Struct<PhantomPinned, (), PhantomPinned>);
+    assert_not_unpin!(Struct<(), PhantomPinned, ()>);
+
+    #[pin_project(project = EnumProj)]
+    enum Enum<T, U> {
+        V1(#[pin(unsafe_exclude_from_unpin)] Inner<T>, #[pin] Inner<U>),
+        V2 {
+            #[pin(unsafe_exclude_from_unpin)]
+            f: PhantomPinned,
+        },
+    }
+
+    assert_unpin!(Enum<PhantomPinned, ()>);
+    assert_not_unpin!(Enum<(), PhantomPinned>);
+
+    #[pin_project]
+    struct TrivialBounds {
+        #[pin(unsafe_exclude_from_unpin)]
+        f: PhantomPinned,
+    }
+
+    assert_unpin!(TrivialBounds);
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_std]
+#![allow(dead_code)]
+
+// Check that the items generated with `crate = <path>` argument don't depend
+// on the `::pin_project` path, when pin-project is renamed or re-exported.
+
+use core::pin::Pin;
+
+use renamed_pin_project::{pin_project, pinned_drop};
+
+pub mod facade {
+    pub use renamed_pin_project as pin_project;
+}
+
+#[pin_project(crate = renamed_pin_project, project_replace)]
+struct Renamed<T, U> {
+    #[pin]
+    pinned: T,
+    unpinned: U,
+}
+
+#[pin_project(crate = renamed_pin_project, project = RenamedEnumProj)]
+enum RenamedEnum<T, U> {
+    Struct {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    },
+    Tuple(#[pin] T, U),
+    Unit,
+}
+
+#[pin_project(PinnedDrop, crate = crate::facade::pin_project)]
+struct Reexported<T, U>(#[pin] T, U);
+
+#[pinned_drop(crate = crate::facade::pin_project)]
+impl<T, U> PinnedDrop for Reexported<T, U> {
+    fn drop(self: Pin<&mut Self>) {
+        let _this = self.project();
+    }
+}
+
+#[facade::pin_project::pin_project(
+    crate = facade::pin_project,
+    project = ReexportedEnumProj,
+    project_ref = ReexportedEnumProjRef,
+    project_replace = ReexportedEnumProjOwn,
+)]
+enum ReexportedEnum<T, U> {
+    Struct {
+        #[pin]
+        pinned: T,
+        unpinned: U,
+    },
+    Tuple(#[pin] T, U),
+    Unit,
+}
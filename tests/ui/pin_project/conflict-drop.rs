@@ -31,4 +31,16 @@ impl<T, U> Drop for Bar<T, U> {
     fn drop(&mut self) {}
 }
 
+#[pin_project(project_variants)] //~ ERROR E0119
+enum Baz<T> {
+    #[pin(drop = drop_baz)]
+    V(#[pin] T),
+}
+
+fn drop_baz<T>(_: BazVProj<'_, T>) {}
+
+impl<T> Drop for Baz<T> {
+    fn drop(&mut self) {}
+}
+
 fn main() {}
@@ -10,20 +10,139 @@ mod pin_argument {
     }
 
     #[pin_project]
-    struct TupleStruct(#[pin(foo)] ()); //~ ERROR unexpected token in attribute
+    struct TupleStruct(#[pin(foo)] ()); //~ ERROR unexpected argument: foo
 
     #[pin_project]
     enum EnumTuple {
-        V(#[pin(foo)] ()), //~ ERROR unexpected token in attribute
+        V(#[pin(foo)] ()), //~ ERROR unexpected argument: foo
     }
 
     #[pin_project]
     enum EnumStruct {
         V {
-            #[pin(foo)] //~ ERROR unexpected token in attribute
+            #[pin(foo)] //~ ERROR unexpected argument: foo
             f: (),
         },
     }
+
+    #[pin_project]
+    struct NameValue {
+        #[pin = ()] //~ ERROR unexpected token in attribute
+        f: (),
+    }
+
+    #[pin_project]
+    struct DuplicateProject {
+        #[pin(project, project)] //~ ERROR duplicate `project` argument
+        f: (),
+    }
+
+    #[pin_project]
+    struct ProjectValue {
+        #[pin(project = A)] //~ ERROR expected `,`
+        f: (),
+    }
+
+    #[pin_project]
+    struct DuplicateSkip {
+        #[pin(skip, skip)] //~ ERROR duplicate `skip` argument
+        f: (),
+    }
+
+    #[pin_project]
+    struct SkipWithProject {
+        #[pin(skip, project)] //~ ERROR arguments `skip` and `project` are mutually exclusive
+        f: (),
+    }
+
+    #[pin_project]
+    struct DuplicateElements {
+        #[pin(elements, elements)] //~ ERROR duplicate `elements` argument
+        f: [(); 1],
+    }
+
+    #[pin_project]
+    struct SkipWithElements {
+        #[pin(skip, elements)] //~ ERROR arguments `skip` and `elements` are mutually exclusive
+        f: [(); 1],
+    }
+
+    #[pin_project]
+    struct ElementsWithProject {
+        #[pin(elements, project)] //~ ERROR arguments `elements` and `project` are mutually exclusive
+        f: [(); 1],
+    }
+
+    #[pin_project]
+    struct ElementsNotArray {
+        #[pin(elements)]
+        f: (), //~ ERROR `elements` argument may only be used on fields of array or slice types
+    }
+
+    #[pin_project]
+    struct SkipWithProjectAttr {
+        #[pin(skip, project_attr(doc(hidden)))] //~ ERROR arguments `skip` and `project_attr` are mutually exclusive
+        f: (),
+    }
+
+    #[pin_project]
+    struct ProjectAttr {
+        #[pin(project_attr)] //~ ERROR expected `project_attr(<attribute>, ...)`, found `project_attr`
+        f: (),
+    }
+
+    #[pin_project]
+    struct DuplicateExcludeFromUnpin {
+        #[pin(unsafe_exclude_from_unpin, unsafe_exclude_from_unpin)] //~ ERROR duplicate `unsafe_exclude_from_unpin` argument
+        f: (),
+    }
+
+    #[pin_project(UnsafeUnpin)]
+    struct ExcludeFromUnpinWithUnsafeUnpin {
+        #[pin(unsafe_exclude_from_unpin)] //~ ERROR arguments `unsafe_exclude_from_unpin` and `UnsafeUnpin` are mutually exclusive
+        f: (),
+    }
+
+    #[pin_project(!Unpin)]
+    enum ExcludeFromUnpinWithNotUnpin {
+        V(#[pin(unsafe_exclude_from_unpin)] ()), //~ ERROR arguments `unsafe_exclude_from_unpin` and `!Unpin` are mutually exclusive
+    }
+
+    #[pin_project]
+    enum VariantSkip {
+        #[pin(skip)] //~ ERROR unexpected argument: skip
+        V(()),
+    }
+
+    #[pin_project(project_variants)]
+    enum VariantDrop1 {
+        #[pin(drop)] //~ ERROR expected `drop = <path>`, found `drop`
+        V(()),
+    }
+
+    #[pin_project(project_variants)]
+    enum VariantDrop2 {
+        #[pin(drop = )] //~ ERROR expected `drop = <path>`, found `drop =`
+        V(()),
+    }
+
+    #[pin_project(project_variants)]
+    enum VariantDrop3 {
+        #[pin(drop = a, drop = b)] //~ ERROR duplicate `drop` argument
+        V(()),
+    }
+
+    #[pin_project]
+    enum VariantDrop4 {
+        #[pin(drop = a)] //~ ERROR `#[pin(drop = <path>)]` attribute requires `project_variants` argument
+        V(()),
+    }
+
+    #[pin_project(PinnedDrop, project_variants)] //~ ERROR `PinnedDrop` argument and `#[pin(drop = <path>)]` attribute are mutually exclusive
+    enum VariantDrop5 {
+        #[pin(drop = a)]
+        V(()),
+    }
 }
 
 mod pin_attribute {
@@ -62,6 +181,13 @@ mod pin_attribute {
             f: (),
         },
     }
+
+    #[pin_project]
+    struct DuplicateArgsStruct {
+        #[pin(project)]
+        #[pin(project)] //~ ERROR duplicate #[pin] attribute
+        f: (),
+    }
 }
 
 mod pin_item {
@@ -80,6 +206,13 @@ mod pin_item {
         V(()),
     }
 
+    #[pin_project]
+    enum DuplicateVariantArgs {
+        #[pin(project_attr(doc(hidden)))]
+        #[pin(project_ref_attr(doc(hidden)))] //~ ERROR duplicate #[pin] attribute
+        V(()),
+    }
+
     #[pin_project]
     #[pin] //~ ERROR may only be used on fields of structs or variants
     enum Enum {
@@ -144,11 +277,11 @@ mod pin_project_argument {
     #[pin_project(project_replace = A)] // Ok
     struct ProjectReplaceWithoutReplace(#[pin] ());
 
-    #[pin_project(PinnedDrop, project_replace)] //~ ERROR arguments `PinnedDrop` and `project_replace` are mutually exclusive
-    struct PinnedDropWithProjectReplace1(#[pin] ());
+    #[pin_project(PinnedDrop = )] //~ ERROR expected `PinnedDrop = <path>`, found `PinnedDrop =`
+    struct PinnedDropFn1(#[pin] ());
 
-    #[pin_project(project_replace, UnsafeUnpin, PinnedDrop)] //~ ERROR arguments `PinnedDrop` and `project_replace` are mutually exclusive
-    struct PinnedDropWithProjectReplace2(#[pin] ());
+    #[pin_project(PinnedDrop = a, PinnedDrop = b)] //~ ERROR duplicate `PinnedDrop` argument
+    struct PinnedDropFn2(#[pin] ());
 
     #[pin_project(UnsafeUnpin, !Unpin)] //~ ERROR arguments `UnsafeUnpin` and `!Unpin` are mutually exclusive
     struct UnsafeUnpinWithNotUnpin1(#[pin] ());
@@ -162,6 +295,27 @@ mod pin_project_argument {
     #[pin_project(Unpin)] //~ ERROR unexpected argument
     struct NotUnpin2(#[pin] ());
 
+    #[pin_project(Unpin where)] //~ ERROR expected `Unpin where <predicates>`, found `Unpin where`
+    struct UnpinWhere1(#[pin] ());
+
+    #[pin_project(Unpin where T: Send, project = A)] //~ ERROR expected `:`
+    struct UnpinWhere2<T>(#[pin] T);
+
+    #[pin_project(UnsafeUnpin, Unpin where (): Send)] //~ ERROR arguments `UnsafeUnpin` and `Unpin where` are mutually exclusive
+    struct UnpinWhereWithUnsafeUnpin(#[pin] ());
+
+    #[pin_project(!Unpin, Unpin where (): Send)] //~ ERROR arguments `!Unpin` and `Unpin where` are mutually exclusive
+    struct UnpinWhereWithNotUnpin(#[pin] ());
+
+    #[pin_project(!Unpin, negative_impl, negative_impl)] //~ ERROR duplicate `negative_impl` argument
+    struct DuplicateNegativeImpl(#[pin] ());
+
+    #[pin_project(negative_impl)] //~ ERROR `negative_impl` argument requires `!Unpin` argument
+    struct NegativeImplWithoutNotUnpin(#[pin] ());
+
+    #[pin_project(UnsafeUnpin, negative_impl)] //~ ERROR `negative_impl` argument requires `!Unpin` argument
+    struct NegativeImplWithUnsafeUnpin(#[pin] ());
+
     #[pin_project(project)] //~ ERROR expected `project = <identifier>`, found `project`
     struct Project1(#[pin] ());
 
@@ -193,6 +347,152 @@ mod pin_project_argument {
     enum ProjectReplaceEnum {
         V(#[pin] ()),
     }
+
+    #[pin_project(accessors, accessors)] //~ ERROR duplicate `accessors` argument
+    struct DuplicateAccessors {
+        #[pin]
+        f: (),
+    }
+
+    #[pin_project(accessors)] //~ ERROR `accessors` argument may only be used on structs with named fields
+    struct AccessorsTupleStruct(#[pin] ());
+
+    #[pin_project(accessors)] //~ ERROR `accessors` argument may only be used on structs with named fields
+    enum AccessorsEnum {
+        V {
+            #[pin]
+            f: (),
+        },
+    }
+
+    #[pin_project(project_replace, project_take, project_take)] //~ ERROR duplicate `project_take` argument
+    struct DuplicateProjectTake(#[pin] ());
+
+    #[pin_project(project_take)] //~ ERROR `project_take` argument requires `project_replace` argument
+    struct ProjectTakeWithoutReplace(#[pin] ());
+
+    #[pin_project(project_replace, project_take, project_replace_fn = project_take)] //~ ERROR name `project_take` is already used by the method generated by `project_take` argument
+    struct ProjectTakeAndProjectReplaceFn(#[pin] ());
+
+    #[pin_project(project_replace, project_replace_fn = project_take)] // Ok
+    struct ProjectReplaceFnTake(#[pin] ());
+
+    #[pin_project(project_replace, project_replace_with, project_replace_with)] //~ ERROR duplicate `project_replace_with` argument
+    struct DuplicateProjectReplaceWith(#[pin] ());
+
+    #[pin_project(project_replace_with)] //~ ERROR `project_replace_with` argument requires `project_replace` argument
+    struct ProjectReplaceWithWithoutReplace(#[pin] ());
+
+    #[pin_project(project_replace, project_replace_with, project_fn = project_replace_with)] //~ ERROR name `project_replace_with` is already used by the method generated by `project_replace_with` argument
+    struct ProjectReplaceWithAndProjectFn(#[pin] ());
+
+    #[pin_project(project_replace, project_replace_fn = project_replace_with)] // Ok
+    struct ProjectReplaceFnReplaceWith(#[pin] ());
+
+    #[pin_project(project_replace = A, project_replace_with)] //~ ERROR `project_replace_with` argument requires `project` argument when used on enums
+    enum ProjectReplaceWithEnumWithoutProject {
+        V(#[pin] ()),
+    }
+
+    #[pin_project(project_replace, project_transition, project_transition)] //~ ERROR duplicate `project_transition` argument
+    struct DuplicateProjectTransition(#[pin] ());
+
+    #[pin_project(project_transition)] //~ ERROR `project_transition` argument requires `project_replace` argument
+    struct ProjectTransitionWithoutReplace(#[pin] ());
+
+    #[pin_project(into_parts)] //~ ERROR expected `into_parts = <identifier>`, found `into_parts`
+    struct IntoParts1(#[pin] ());
+
+    #[pin_project(into_parts = A, into_parts = B)] //~ ERROR duplicate `into_parts` argument
+    struct IntoParts2(#[pin] ());
+
+    #[pin_project(project_variants, project_variants)] //~ ERROR duplicate `project_variants` argument
+    enum DuplicateProjectVariants {
+        V(#[pin] ()),
+    }
+
+    #[pin_project(project_variants)] //~ ERROR `project_variants` argument may only be used on enums
+    struct ProjectVariantsStruct(#[pin] ());
+
+    #[pin_project(crate)] //~ ERROR expected `crate = <path>`, found `crate`
+    struct Crate1(#[pin] ());
+
+    #[pin_project(crate = )] //~ ERROR expected `crate = <path>`, found `crate =`
+    struct Crate2(#[pin] ());
+
+    #[pin_project(crate = pin_project, crate = pin_project)] //~ ERROR duplicate `crate` argument
+    struct Crate3(#[pin] ());
+
+    #[pin_project(project_vis)] //~ ERROR expected `project_vis = <visibility>`, found `project_vis`
+    struct ProjectVis1(#[pin] ());
+
+    #[pin_project(project_vis = )] //~ ERROR expected `project_vis = <visibility>`, found `project_vis =`
+    struct ProjectVis2(#[pin] ());
+
+    #[pin_project(project_vis = crate)] //~ ERROR expected visibility
+    struct ProjectVis3(#[pin] ());
+
+    #[pin_project(project_vis = pub, project_vis = pub(crate))] //~ ERROR duplicate `project_vis` argument
+    struct ProjectVis4(#[pin] ());
+
+    #[pin_project(PinProject, PinProject)] //~ ERROR duplicate `PinProject` argument
+    struct DuplicatePinProject(#[pin] ());
+
+    #[pin_project(PinProject, !project)] //~ ERROR arguments `PinProject` and `!project` are mutually exclusive
+    struct PinProjectWithNotProject(#[pin] ());
+
+    #[pin_project(PinProject, !project_ref)] //~ ERROR arguments `PinProject` and `!project_ref` are mutually exclusive
+    struct PinProjectWithNotProjectRef(#[pin] ());
+
+    #[pin_project(PinProject, project = A)] //~ ERROR `PinProject` argument requires the named projected types to be as visible as the original type
+    pub struct PinProjectNamedPub(#[pin] ());
+
+    #[pin_project(PinProject, project_ref = B, project_vis = pub(crate))] //~ ERROR `PinProject` argument requires the named projected types to be as visible as the original type
+    pub struct PinProjectNamedPubCrate(#[pin] ());
+
+    #[pin_project(PinProject, project = C, project_vis = pub)] // Ok
+    pub struct PinProjectNamedProjectVis(#[pin] ());
+
+    #[pin_project(PinProject)] // Ok
+    pub struct PinProjectUnnamed(#[pin] ());
+
+    #[pin_project(!Foo)] //~ ERROR expected `Unpin`, `project`, or `project_ref`
+    struct NotFoo(#[pin] ());
+
+    #[pin_project(!project_ref, !project_ref)] //~ ERROR duplicate `!project_ref` argument
+    struct DuplicateNotProjectRef(#[pin] ());
+
+    #[pin_project(project_fn = a, project_fn = b)] //~ ERROR duplicate `project_fn` argument
+    struct DuplicateProjectFn(#[pin] ());
+
+    #[pin_project(project_fn = _)] //~ ERROR expected identifier
+    struct ProjectFnInfer(#[pin] ());
+
+    #[pin_project(project_fn)] //~ ERROR expected `project_fn = <identifier>`, found `project_fn`
+    struct ProjectFn1(#[pin] ());
+
+    #[pin_project(!project, project_fn = a)] //~ ERROR arguments `!project` and `project_fn` are mutually exclusive
+    struct NotProjectWithProjectFn(#[pin] ());
+
+    #[pin_project(!project_ref, project_ref = A)] //~ ERROR arguments `!project_ref` and `project_ref = <identifier>` are mutually exclusive
+    struct NotProjectRefWithProjectRef(#[pin] ());
+
+    #[pin_project(project_replace_fn = a)] //~ ERROR `project_replace_fn` argument requires `project_replace` argument
+    struct ProjectReplaceFnWithoutProjectReplace(#[pin] ());
+
+    #[pin_project(project_fn = a, project_ref_fn = a)] //~ ERROR name `a` is already specified by `project_fn` argument
+    struct SameProjectFn(#[pin] ());
+
+    #[pin_project(project_fn = a)] //~ ERROR `project_fn` argument requires `project` argument when used on enums
+    enum ProjectFnEnum {
+        V(#[pin] ()),
+    }
+
+    #[pin_project(project_ref_attr)] //~ ERROR expected `project_ref_attr(<attribute>, ...)`, found `project_ref_attr`
+    struct ProjectRefAttr1(#[pin] ());
+
+    #[pin_project(project_replace_attr = derive(Debug))] //~ ERROR expected `project_replace_attr(<attribute>, ...)`, found `project_replace_attr`
+    struct ProjectReplaceAttr1(#[pin] ());
 }
 
 mod pin_project_conflict_naming {
@@ -215,6 +515,75 @@ mod pin_project_conflict_naming {
 
     #[pin_project(project_ref = A, project_replace = A)] //~ ERROR name `A` is already specified by `project_ref` argument
     struct ProjRefAndProjOwn(#[pin] ());
+
+    #[pin_project(into_parts = OrigAndParts)] //~ ERROR name `OrigAndParts` is the same as the original type name
+    struct OrigAndParts(#[pin] ());
+
+    #[pin_project(project_replace = A, into_parts = A)] //~ ERROR name `A` is already specified by `project_replace` argument
+    struct ProjOwnAndParts(#[pin] ());
+
+    #[pin_project(accessors)]
+    struct AccessorAndProject {
+        project: (), //~ ERROR accessor method `project` for field `project` conflicts with another method generated by #[pin_project] attribute
+    }
+
+    #[pin_project(accessors, project_replace)]
+    struct AccessorAndProjectReplace {
+        #[pin]
+        f: (),
+        project_replace: (), //~ ERROR accessor method `project_replace` for field `project_replace` conflicts with another method generated by #[pin_project] attribute
+    }
+
+    #[pin_project(accessors, project_fn = f)]
+    struct AccessorAndProjectFn {
+        #[pin]
+        f: (), //~ ERROR accessor method `f` for field `f` conflicts with another method generated by #[pin_project] attribute
+    }
+
+    #[pin_project(accessors, !project, !project_ref)]
+    struct AccessorAndNotProject {
+        project: (), // Ok
+    }
+
+    #[pin_project(accessors)]
+    struct AccessorAndRefAccessor {
+        x: (),
+        x_ref: (), //~ ERROR accessor method `x_ref` for field `x_ref` conflicts with the accessor method for field `x`; consider renaming one of the fields
+    }
+
+    #[pin_project(accessors)]
+    struct AccessorAndSetAccessor {
+        #[pin]
+        x: (),
+        set_x: (), //~ ERROR accessor method `set_x` for field `set_x` conflicts with the accessor method for field `x`; consider renaming one of the fields
+    }
+
+    #[pin_project(project = VariantAndProjectRefProj, project_ref = VariantAndProjectRefProjRef, project_variants)]
+    enum VariantAndProjectRef {
+        Ref(#[pin] ()), //~ ERROR method `project_ref` for variant `Ref` conflicts with another method generated by #[pin_project] attribute
+    }
+
+    #[pin_project(project_replace = VariantAndProjectReplaceProjOwn, project_variants)]
+    enum VariantAndProjectReplace {
+        A(#[pin] ()),
+        Replace(()), //~ ERROR method `project_replace` for variant `Replace` conflicts with another method generated by #[pin_project] attribute
+    }
+
+    #[pin_project(project_variants)]
+    enum VariantAndNotProjectRef {
+        Ref(#[pin] ()), // Ok
+    }
+
+    #[pin_project(project_variants)]
+    enum VariantAndRefVariant {
+        A(#[pin] ()),
+        ARef(()), //~ ERROR method `project_a_ref` for variant `ARef` conflicts with the method for variant `A`; consider renaming one of the variants
+    }
+
+    #[pin_project(project = VariantTypeAProj, project_variants)]
+    enum VariantType {
+        A(#[pin] ()), //~ ERROR type `VariantTypeAProj` for variant `A` conflicts with another type generated by #[pin_project] attribute; consider renaming the variant
+    }
 }
 
 mod pin_project_attribute {
@@ -228,30 +597,9 @@ mod pin_project_attribute {
 mod pin_project_item {
     use pin_project::pin_project;
 
-    #[pin_project]
-    struct Struct {} //~ ERROR may not be used on structs with zero fields
-
-    #[pin_project]
-    struct TupleStruct(); //~ ERROR may not be used on structs with zero fields
-
-    #[pin_project]
-    struct UnitStruct; //~ ERROR may not be used on structs with zero fields
-
     #[pin_project]
     enum EnumEmpty {} //~ ERROR may not be used on enums without variants
 
-    #[pin_project]
-    enum EnumDiscriminant {
-        V = 2, //~ ERROR may not be used on enums with discriminants
-    }
-
-    #[pin_project]
-    enum EnumZeroFields {
-        Unit, //~ ERROR may not be used on enums with zero fields
-        Tuple(),
-        Struct {},
-    }
-
     #[pin_project]
     union Union {
         //~^ ERROR may only be used on structs or enums
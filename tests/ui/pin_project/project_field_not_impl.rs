@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pin_project::pin_project;
+
+// `PinProject` is not implemented without `PinProject` argument.
+#[pin_project(project = InnerProj, project_vis = pub)]
+pub struct Inner<T> {
+    #[pin]
+    pinned: T,
+}
+
+#[pin_project]
+pub struct Outer<T> {
+    #[pin(project)] //~ ERROR E0277
+    inner: Inner<T>,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::pin::Pin;
+
+use pin_project::pin_project;
+
+#[pin_project(project_replace, project_replace_with)]
+struct Struct<T> {
+    #[pin]
+    f: T,
+}
+
+fn main() {
+    let mut x = Struct { f: 1 };
+    let x = Pin::new(&mut x);
+    let mut escaped = None;
+    let _ = x.project_replace_with(Struct { f: 2 }, |this| {
+        escaped = Some(this.f); //~ ERROR borrowed data escapes outside of closure
+    });
+    let _ = escaped;
+}
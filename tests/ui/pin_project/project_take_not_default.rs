@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::pin::Pin;
+
+use pin_project::pin_project;
+
+#[pin_project(project_replace, project_take)]
+struct Struct<T> {
+    #[pin]
+    f: T,
+}
+
+#[pin_project(project_replace, project_take)]
+struct NotDefault {
+    #[pin]
+    f: (),
+}
+
+fn main() {
+    let mut x = Struct { f: NotDefault { f: () } };
+    let _ = Pin::new(&mut x).project_take(); //~ ERROR E0277
+
+    let mut x = NotDefault { f: () };
+    let _ = Pin::new(&mut x).project_take(); //~ ERROR E0277
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![forbid(unsafe_code)]
+
+use std::marker::PhantomPinned;
+
+use pin_project::pin_project;
+
+#[pin_project]
+struct Struct<T> {
+    #[pin(unsafe_exclude_from_unpin)] //~ ERROR usage of an `unsafe` block
+    f1: PhantomPinned,
+    #[pin]
+    f2: T,
+}
+
+fn main() {}
@@ -48,4 +48,23 @@ pub mod pub_crate_use {
     pub(crate) use crate::pub_crate::{DProj, DProjRef, RProj, RProjOwn, RProjRef};
 }
 
+mod pub_project_vis {
+    use pin_project::pin_project;
+
+    #[pin_project(project = DProj, project_ref = DProjRef, project_vis = pub)]
+    pub struct Default(());
+
+    #[pin_project(
+        project = RProj,
+        project_ref = RProjRef,
+        project_replace = RProjOwn,
+        project_vis = pub,
+    )]
+    pub struct Replace(());
+}
+pub mod pub_project_vis_use {
+    // Ok
+    pub use crate::pub_project_vis::{DProj, DProjRef, RProj, RProjOwn, RProjRef};
+}
+
 fn main() {}
@@ -20,6 +20,30 @@ mod argument {
     impl PinnedDrop for UnexpectedArg2 {
         fn drop(self: Pin<&mut Self>) {}
     }
+
+    #[pin_project(PinnedDrop)]
+    struct Crate1(());
+
+    #[pinned_drop(crate)] //~ ERROR expected `crate = <path>`, found `crate`
+    impl PinnedDrop for Crate1 {
+        fn drop(self: Pin<&mut Self>) {}
+    }
+
+    #[pin_project(PinnedDrop)]
+    struct Crate2(());
+
+    #[pinned_drop(crate = pin_project, crate = pin_project)] //~ ERROR duplicate `crate` argument
+    impl PinnedDrop for Crate2 {
+        fn drop(self: Pin<&mut Self>) {}
+    }
+
+    #[pin_project(PinnedDrop)]
+    struct Crate3(());
+
+    #[pinned_drop(crate = ::pin_project)] // Ok
+    impl PinnedDrop for Crate3 {
+        fn drop(self: Pin<&mut Self>) {}
+    }
 }
 
 mod attribute {
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::pin::Pin;
+
+use pin_project::{pin_project, pinned_drop};
+
+fn drop_struct<T>(_this: Pin<&mut Struct<T>>) {}
+
+#[pin_project(PinnedDrop = drop_struct)]
+struct Struct<T> {
+    #[pin]
+    f: T,
+}
+
+#[pinned_drop]
+impl<T> PinnedDrop for Struct<T> { //~ ERROR E0119
+    fn drop(self: Pin<&mut Self>) {}
+}
+
+fn drop_other(_this: Pin<&mut ()>) {}
+
+#[pin_project(PinnedDrop = drop_other)]
+struct InvalidSig<T> {
+    #[pin]
+    f: T,
+} //~^^^^ ERROR E0308
+
+unsafe fn drop_unsafe<T>(_this: Pin<&mut UnsafeFn<T>>) {}
+
+#[pin_project(PinnedDrop = drop_unsafe)]
+struct UnsafeFn<T> {
+    #[pin]
+    f: T,
+} //~^^^^ ERROR E0308
+
+static mut HOOK: fn(Pin<&mut StaticMut>) = |_| {};
+
+#[pin_project(project_replace, PinnedDrop = HOOK)] //~ ERROR E0133
+struct StaticMut {
+    #[pin]
+    f: (),
+}
+
+fn main() {}
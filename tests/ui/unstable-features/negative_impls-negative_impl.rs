@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(negative_impls)]
+
+use std::marker::PhantomPinned;
+
+use pin_project::pin_project;
+
+#[pin_project(!Unpin, negative_impl)]
+struct Foo<Pinned, Unpinned> {
+    #[pin]
+    pinned: Pinned,
+    unpinned: Unpinned,
+}
+
+impl Unpin for Foo<(), ()> {} //~ ERROR E0751
+
+#[pin_project(!Unpin, negative_impl)]
+struct Bar<Pinned, Unpinned> {
+    #[pin]
+    pinned: Pinned,
+    unpinned: Unpinned,
+}
+
+fn is_unpin<T: Unpin>() {}
+
+fn main() {
+    is_unpin::<Bar<(), ()>>(); //~ ERROR E0277
+    is_unpin::<Bar<PhantomPinned, ()>>(); //~ ERROR E0277
+}